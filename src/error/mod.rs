@@ -0,0 +1,69 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+//! Error types returned by fallible operations.
+
+use core::alloc::Layout;
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// The error returned by fallible capacity-reserving methods.
+///
+/// This is returned by e.g. [`IdentityMap::try_reserve`](crate::IdentityMap::try_reserve) and [`IdentitySet::try_reserve`](crate::IdentitySet::try_reserve) in place of panicking, allowing callers to recover from allocation failure.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TryReserveError {
+	/// The requested capacity exceeds `isize::MAX` bytes, or no valid [layout](Layout) could otherwise be computed for it.
+	CapacityOverflow,
+
+	/// The allocator was unable to fulfil the requested allocation.
+	AllocError {
+		/// The layout that could not be allocated.
+		layout: Layout,
+	},
+}
+
+impl Display for TryReserveError {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::CapacityOverflow => write!(f, "memory allocation failed due to capacity overflow"),
+
+			Self::AllocError { layout } => write!(f, "memory allocation of {} bytes failed", layout.size()),
+		}
+	}
+}
+
+impl Error for TryReserveError { }
+
+/// The error returned by fallible insertion methods.
+///
+/// This is returned by e.g. [`IdentityMap::try_insert`](crate::IdentityMap::try_insert) and [`IdentitySet::try_insert`](crate::IdentitySet::try_insert) in place of panicking.
+/// Unlike plainly returning a [`TryReserveError`], this also hands the un-inserted key and value back to the caller so that no data is lost on failure.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TryInsertError<K, V> {
+	/// The underlying allocation failure.
+	pub error: TryReserveError,
+
+	/// The key that could not be inserted.
+	pub key: K,
+
+	/// The value that could not be inserted.
+	pub value: V,
+}
+
+impl<K, V> Display for TryInsertError<K, V> {
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		Display::fmt(&self.error, f)
+	}
+}
+
+impl<K, V> Error for TryInsertError<K, V>
+where
+	K: fmt::Debug,
+	V: fmt::Debug,
+{
+	#[inline(always)]
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		Some(&self.error)
+	}
+}
@@ -0,0 +1,40 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+//! The [`IdentityIndex`] trait and its standard implementations.
+
+/// A key type that can be mapped directly to a bounded, dense array slot.
+///
+/// Implementing this trait for a key type `K` opts it into the [`IdentityTable`](crate::identity_table::IdentityTable) backend, which trades the ordered, comparison-based [`IdentityMap`](crate::IdentityMap) for direct-address lookup: [`index`](Self::index) is used as a raw offset into a flat slot buffer, giving *O*(1) `get`/`insert`/`remove` with no key comparisons at all.
+///
+/// [`DOMAIN`](Self::DOMAIN) bounds the size of that buffer, making the memory blow-up of this strategy explicit at the type level.
+/// Only implement this trait for key types whose domain is small enough to allocate outright -- e.g. `u8` or `u16`, but not `u32` or `u64`.
+pub trait IdentityIndex {
+	/// The total count of distinct values `Self` can take on.
+	///
+	/// This is also the exact number of slots an [`IdentityTable`](crate::identity_table::IdentityTable) keyed by `Self` allocates.
+	const DOMAIN: usize;
+
+	/// Maps `self` to its slot index.
+	///
+	/// The returned value must always be less than [`DOMAIN`](Self::DOMAIN).
+	#[must_use]
+	fn index(&self) -> usize;
+}
+
+impl IdentityIndex for u8 {
+	const DOMAIN: usize = 0x1 << u8::BITS;
+
+	#[inline(always)]
+	fn index(&self) -> usize {
+		*self as usize
+	}
+}
+
+impl IdentityIndex for u16 {
+	const DOMAIN: usize = 0x1 << u16::BITS;
+
+	#[inline(always)]
+	fn index(&self) -> usize {
+		*self as usize
+	}
+}
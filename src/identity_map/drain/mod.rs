@@ -6,6 +6,7 @@ use allocator_api2::alloc::{Allocator, Global};
 use allocator_api2::vec;
 use core::fmt::{self, Debug, Formatter};
 use core::iter::FusedIterator;
+use core::ops::Range as IndexRange;
 
 /// Identity map drain.
 #[must_use]
@@ -21,6 +22,13 @@ impl<'a, K, V, A: Allocator> Drain<'a, K, V, A> {
 		Self { iter }
 	}
 
+	/// Constructs a new identity map drain over a specific index range.
+	#[inline(always)]
+	pub(crate) fn new_range(map: &'a mut IdentityMap<K, V, A>, range: IndexRange<usize>) -> Self {
+		let iter = map.as_mut_vec().drain(range);
+		Self { iter }
+	}
+
 	/// Gets a slice of the key-value pairs.
 	#[inline(always)]
 	#[must_use]
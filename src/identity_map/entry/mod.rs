@@ -0,0 +1,230 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::identity_map::IdentityMap;
+
+use allocator_api2::alloc::{Allocator, Global};
+use core::fmt::{self, Debug, Formatter};
+use core::mem::replace;
+
+/// A view into a single entry of an [identity map](IdentityMap), which may either be vacant or occupied.
+///
+/// This is constructed by the [`entry`](IdentityMap::entry) method on [`IdentityMap`].
+#[must_use]
+pub enum Entry<'a, K, V, A: Allocator = Global> {
+	/// An occupied entry.
+	Occupied(OccupiedEntry<'a, K, V, A>),
+
+	/// A vacant entry.
+	Vacant(VacantEntry<'a, K, V, A>),
+}
+
+impl<'a, K, V, A: Allocator> Entry<'a, K, V, A> {
+	/// Ensures a value is present in the entry, inserting `default` if it is vacant.
+	#[inline]
+	pub fn or_insert(self, default: V) -> &'a mut V {
+		match self {
+			Self::Occupied(entry) => entry.into_mut(),
+			Self::Vacant(entry)   => entry.insert(default),
+		}
+	}
+
+	/// Ensures a value is present in the entry, inserting the result of `default` if it is vacant.
+	#[inline]
+	pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+		match self {
+			Self::Occupied(entry) => entry.into_mut(),
+			Self::Vacant(entry)   => entry.insert(default()),
+		}
+	}
+
+	/// Ensures a value is present in the entry, inserting the result of `default` (given the entry's key) if it is vacant.
+	#[inline]
+	pub fn or_insert_with_key<F: FnOnce(&K) -> V>(self, default: F) -> &'a mut V {
+		match self {
+			Self::Occupied(entry) => entry.into_mut(),
+
+			Self::Vacant(entry) => {
+				let value = default(entry.key());
+				entry.insert(value)
+			}
+		}
+	}
+
+	/// Borrows the entry's key.
+	#[inline]
+	#[must_use]
+	pub fn key(&self) -> &K {
+		match self {
+			Self::Occupied(entry) => entry.key(),
+			Self::Vacant(entry)   => entry.key(),
+		}
+	}
+
+	/// Mutates an occupied entry's value in-place before any subsequent `or_insert*` call.
+	///
+	/// This is a no-op on a vacant entry.
+	#[inline]
+	pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+		if let Self::Occupied(entry) = &mut self {
+			f(entry.get_mut());
+		}
+
+		self
+	}
+}
+
+impl<'a, K, V, A: Allocator> Entry<'a, K, V, A>
+where
+	V: Default,
+{
+	/// Ensures a value is present in the entry, inserting [`V::default`](Default::default) if it is vacant.
+	#[inline(always)]
+	pub fn or_default(self) -> &'a mut V {
+		self.or_insert_with(Default::default)
+	}
+}
+
+impl<K, V, A> Debug for Entry<'_, K, V, A>
+where
+	K: Debug,
+	V: Debug,
+	A: Allocator,
+{
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match self {
+			Self::Occupied(entry) => f.debug_tuple("Entry").field(entry).finish(),
+			Self::Vacant(entry)   => f.debug_tuple("Entry").field(entry).finish(),
+		}
+	}
+}
+
+/// A view into an occupied entry of an [identity map](IdentityMap).
+///
+/// This is part of the [`Entry`] enum.
+#[must_use]
+pub struct OccupiedEntry<'a, K, V, A: Allocator = Global> {
+	map:   &'a mut IdentityMap<K, V, A>,
+	index: usize,
+}
+
+impl<'a, K, V, A: Allocator> OccupiedEntry<'a, K, V, A> {
+	#[inline(always)]
+	pub(crate) fn new(map: &'a mut IdentityMap<K, V, A>, index: usize) -> Self {
+		Self { map, index }
+	}
+
+	/// Borrows the entry's key.
+	#[inline]
+	#[must_use]
+	pub fn key(&self) -> &K {
+		&self.map.as_slice()[self.index].0
+	}
+
+	/// Borrows the entry's value.
+	#[inline]
+	#[must_use]
+	pub fn get(&self) -> &V {
+		&self.map.as_slice()[self.index].1
+	}
+
+	/// Mutably borrows the entry's value.
+	#[inline]
+	#[must_use]
+	pub fn get_mut(&mut self) -> &mut V {
+		&mut self.map.as_mut_slice()[self.index].1
+	}
+
+	/// Converts the entry into a mutable reference to its value, bound by the map's lifetime.
+	#[inline]
+	#[must_use]
+	pub fn into_mut(self) -> &'a mut V {
+		&mut self.map.as_mut_slice()[self.index].1
+	}
+
+	/// Replaces the entry's value, returning the previous one.
+	#[inline]
+	pub fn insert(&mut self, value: V) -> V {
+		replace(self.get_mut(), value)
+	}
+
+	/// Removes the entry, returning its value.
+	#[inline(always)]
+	pub fn remove(self) -> V {
+		self.remove_entry().1
+	}
+
+	/// Removes the entry, returning its key and value.
+	#[inline]
+	pub fn remove_entry(self) -> (K, V) {
+		self.map.as_mut_vec().remove(self.index)
+	}
+}
+
+impl<K, V, A> Debug for OccupiedEntry<'_, K, V, A>
+where
+	K: Debug,
+	V: Debug,
+	A: Allocator,
+{
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f
+			.debug_struct("OccupiedEntry")
+			.field("key",   self.key())
+			.field("value", self.get())
+			.finish()
+	}
+}
+
+/// A view into a vacant entry of an [identity map](IdentityMap).
+///
+/// This is part of the [`Entry`] enum.
+#[must_use]
+pub struct VacantEntry<'a, K, V, A: Allocator = Global> {
+	map:   &'a mut IdentityMap<K, V, A>,
+	index: usize,
+	key:   K,
+}
+
+impl<'a, K, V, A: Allocator> VacantEntry<'a, K, V, A> {
+	#[inline(always)]
+	pub(crate) fn new(map: &'a mut IdentityMap<K, V, A>, index: usize, key: K) -> Self {
+		Self { map, index, key }
+	}
+
+	/// Borrows the entry's key.
+	#[inline(always)]
+	#[must_use]
+	pub fn key(&self) -> &K {
+		&self.key
+	}
+
+	/// Converts the entry into its key.
+	#[inline(always)]
+	#[must_use]
+	pub fn into_key(self) -> K {
+		self.key
+	}
+
+	/// Inserts a value into the entry, splicing the pair into the sorted buffer at the previously-found insertion index.
+	///
+	/// A mutable reference to the newly-inserted value is returned.
+	#[inline]
+	pub fn insert(self, value: V) -> &'a mut V {
+		self.map.as_mut_vec().insert(self.index, (self.key, value));
+
+		&mut self.map.as_mut_slice()[self.index].1
+	}
+}
+
+impl<K, V, A> Debug for VacantEntry<'_, K, V, A>
+where
+	K: Debug,
+	A: Allocator,
+{
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.debug_struct("VacantEntry").field("key", self.key()).finish()
+	}
+}
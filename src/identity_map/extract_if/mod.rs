@@ -0,0 +1,153 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::identity_map::IdentityMap;
+
+use allocator_api2::alloc::Allocator;
+use core::fmt::{self, Debug, Formatter};
+use core::ptr;
+
+/// Identity map extraction iterator.
+///
+/// This iterator is constructed by the [`extract_if`](IdentityMap::extract_if) method on [`IdentityMap`].
+///
+/// Every key-value pair for which the held predicate returns `true` is moved out and yielded by this iterator; the remaining pairs are shifted down to close the resulting gaps, keeping the map contiguous and sorted.
+/// This compaction also happens if the iterator is dropped before being fully consumed, or if the predicate panics.
+#[must_use]
+pub struct ExtractIf<'a, K, V, A, F>
+where
+	A: Allocator,
+	F: FnMut(&K, &mut V) -> bool,
+{
+	map: &'a mut IdentityMap<K, V, A>,
+
+	idx:     usize,
+	del:     usize,
+	old_len: usize,
+
+	panic_flag: bool,
+
+	pred: F,
+}
+
+impl<'a, K, V, A, F> ExtractIf<'a, K, V, A, F>
+where
+	A: Allocator,
+	F: FnMut(&K, &mut V) -> bool,
+{
+	#[inline]
+	pub(crate) fn new(map: &'a mut IdentityMap<K, V, A>, pred: F) -> Self {
+		let old_len = map.len();
+
+		// SAFETY: Zeroing the length is always sound, and it
+		// guarantees that the map cannot observe the
+		// in-progress (and possibly partially read) buffer
+		// if this iterator is leaked or `pred` panics.
+		unsafe { map.as_mut_vec().set_len(0x0) };
+
+		Self { map, idx: 0x0, del: 0x0, old_len, panic_flag: false, pred }
+	}
+}
+
+impl<K, V, A, F> Debug for ExtractIf<'_, K, V, A, F>
+where
+	A: Allocator,
+	F: FnMut(&K, &mut V) -> bool,
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.debug_struct("ExtractIf").finish_non_exhaustive()
+	}
+}
+
+impl<K, V, A, F> Drop for ExtractIf<'_, K, V, A, F>
+where
+	A: Allocator,
+	F: FnMut(&K, &mut V) -> bool,
+{
+	#[inline]
+	fn drop(&mut self) {
+		let tail_len = self.old_len - self.idx;
+
+		if self.del > 0x0 && tail_len > 0x0 {
+			// SAFETY: Both the source and destination ranges
+			// lie within the original, still-allocated buf-
+			// fer, and `dst` always lags behind `src`.
+			unsafe {
+				let ptr = self.map.as_mut_ptr();
+
+				let src = ptr.add(self.idx);
+				let dst = ptr.add(self.idx - self.del);
+
+				ptr::copy(src, dst, tail_len);
+			}
+		}
+
+		// SAFETY: Every index below `old_len - del` now holds
+		// a live, uniquely-owned pair.
+		unsafe { self.map.as_mut_vec().set_len(self.old_len - self.del) };
+
+		// Remove every matching pair that was not yet visited,
+		// unless `pred` has already panicked.
+		if !self.panic_flag {
+			let mut visited = self.idx - self.del;
+			let pred = &mut self.pred;
+
+			self.map.retain(|key, value| {
+				if visited > 0x0 { visited -= 0x1; return true };
+				!pred(key, value)
+			});
+		}
+	}
+}
+
+impl<K, V, A, F> Iterator for ExtractIf<'_, K, V, A, F>
+where
+	A: Allocator,
+	F: FnMut(&K, &mut V) -> bool,
+{
+	type Item = (K, V);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		while self.idx < self.old_len {
+			let i = self.idx;
+
+			// SAFETY: `i` is in bounds of the original buffer,
+			// which stays allocated (and, besides compaction
+			// of already-visited slots, untouched) for as
+			// long as this iterator lives.
+			let pair = unsafe { &mut *self.map.as_mut_ptr().add(i) };
+
+			self.panic_flag = true;
+			let matches = (self.pred)(&pair.0, &mut pair.1);
+			self.panic_flag = false;
+			self.idx += 1;
+
+			if matches {
+				self.del += 1;
+
+				// SAFETY: This pair is moved out here and will
+				// not be read again: it is either overwritten
+				// by a later retained pair or falls past the
+				// map's corrected length.
+				return Some(unsafe { ptr::read(pair) });
+			} else if self.del > 0x0 {
+				// SAFETY: `dst` always lags behind `src`, and
+				// both lie within the original buffer.
+				unsafe {
+					let src: *const (K, V) = pair;
+					let dst = self.map.as_mut_ptr().add(i - self.del);
+
+					ptr::copy_nonoverlapping(src, dst, 0x1);
+				}
+			}
+		}
+
+		None
+	}
+
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0x0, Some(self.old_len - self.idx))
+	}
+}
@@ -8,24 +8,34 @@ mod serde;
 
 use crate::identity_map::{
 	Drain,
+	Entry,
+	ExtractIf,
 	IntoIter,
 	IntoKeys,
 	IntoValues,
 	Iter,
 	IterMut,
 	Keys,
+	OccupiedEntry,
+	Range,
+	RangeMut,
+	Slice,
+	VacantEntry,
 	Values,
 	ValuesMut,
 };
 
+use crate::{TryInsertError, TryReserveError};
+
 use allocator_api2::alloc::{Allocator, Global};
 use allocator_api2::vec::Vec;
+use core::alloc::Layout;
 use core::borrow::Borrow;
 use core::cmp::Ordering;
 use core::fmt::{self, Debug, Formatter};
 use core::hash::{Hash, Hasher};
 use core::mem::swap;
-use core::ops::Index;
+use core::ops::{Bound, Index, RangeBounds};
 
 /// An ordered identity map.
 ///
@@ -62,6 +72,18 @@ impl<K, V> IdentityMap<K, V> {
 		Self::with_capacity_in(cap, Global)
 	}
 
+	/// Preallocates a new identity map, without panicking.
+	///
+	/// This is the fallible counterpart to [`with_capacity`](Self::with_capacity).
+	///
+	/// # Errors
+	///
+	/// If `[(K, V); cap]` could not be allocated using the global allocator, then this function returns an appropriate [`TryReserveError`] instead of panicking.
+	#[inline]
+	pub fn try_with_capacity(cap: usize) -> Result<Self, TryReserveError> {
+		Self::try_with_capacity_in(cap, Global)
+	}
+
 	/// Constructs a new identity map from raw parts.
 	///
 	/// # Safety
@@ -102,6 +124,23 @@ impl<K, V, A: Allocator> IdentityMap<K, V, A> {
 		Self { buf }
 	}
 
+	/// Preallocates a new identity map with a specific allocator, without panicking.
+	///
+	/// This is the fallible counterpart to [`with_capacity_in`](Self::with_capacity_in).
+	/// It is intended for allocator-constrained contexts that must recover from failed growth instead of aborting the process.
+	///
+	/// # Errors
+	///
+	/// If `[(K, V); cap]` could not be allocated with the given allocator, then this method returns an appropriate [`TryReserveError`] instead of panicking.
+	#[inline]
+	pub fn try_with_capacity_in(cap: usize, alloc: A) -> Result<Self, TryReserveError> {
+		let mut map = Self::new_in(alloc);
+
+		map.try_reserve(cap)?;
+
+		Ok(map)
+	}
+
 	/// Constructs a new identity map from raw parts.
 	///
 	/// # Safety
@@ -133,6 +172,17 @@ impl<K, V, A: Allocator> IdentityMap<K, V, A> {
 		self.buf.retain_mut(|(k, v)| f(&*k, v));
 	}
 
+	/// Extracts all key-value pairs matching a predicate, yielding them through an iterator.
+	///
+	/// Every pair `(k, v)` for which `pred(k, v)` returns `true` is moved out of the map and yielded.
+	/// The remaining pairs stay in place and in order.
+	///
+	/// If the returned iterator is dropped before being fully consumed, it will still remove and drop all matching pairs from the remainder of the map.
+	#[inline(always)]
+	pub fn extract_if<F: FnMut(&K, &mut V) -> bool>(&mut self, pred: F) -> ExtractIf<'_, K, V, A, F> {
+		ExtractIf::new(self, pred)
+	}
+
 	/// Clears the map.
 	///
 	/// All contained keys and values are dropped after a call to this method.
@@ -151,7 +201,55 @@ impl<K, V, A: Allocator> IdentityMap<K, V, A> {
 	#[inline(always)]
 	#[track_caller]
 	pub fn reserve(&mut self, len: usize) {
-		self.buf.reserve(len);
+		self.try_reserve(len).unwrap();
+	}
+
+	/// Reserves additional capacity for the map, without panicking.
+	///
+	/// This is the fallible counterpart to [`reserve`](Self::reserve).
+	/// It is intended for allocator-constrained contexts that must recover from failed growth instead of aborting the process.
+	///
+	/// # Errors
+	///
+	/// If the requested capacity would overflow [`isize::MAX`] bytes, or the allocator could not fulfil the allocation, then this method returns an appropriate [`TryReserveError`] instead of panicking.
+	#[inline]
+	pub fn try_reserve(&mut self, len: usize) -> Result<(), TryReserveError> {
+		let cap = self.buf.len().checked_add(len).ok_or(TryReserveError::CapacityOverflow)?;
+
+		let layout = Layout::array::<(K, V)>(cap).map_err(|_| TryReserveError::CapacityOverflow)?;
+
+		self.buf.try_reserve(len).map_err(|_| TryReserveError::AllocError { layout })
+	}
+
+	/// Reserves the exact additional capacity for the map, without over-allocating.
+	///
+	/// Unlike [`reserve`](Self::reserve), which may grow the buffer geometrically to amortise the cost of repeated insertions, this grows the buffer to hold precisely `len` more elements than its current length.
+	/// Prefer [`reserve`](Self::reserve) when inserting incrementally; this method suits a single, known-size reservation ahead of a bulk fill.
+	///
+	/// # Panics
+	///
+	/// This method will panic if the internal buffer could not be grown.
+	/// It will also panic if the new capacity of the map is greater than [`isize::MAX`].
+	#[inline(always)]
+	#[track_caller]
+	pub fn reserve_exact(&mut self, len: usize) {
+		self.try_reserve_exact(len).unwrap();
+	}
+
+	/// Reserves the exact additional capacity for the map, without panicking.
+	///
+	/// This is the fallible counterpart to [`reserve_exact`](Self::reserve_exact).
+	///
+	/// # Errors
+	///
+	/// If the requested capacity would overflow [`isize::MAX`] bytes, or the allocator could not fulfil the allocation, then this method returns an appropriate [`TryReserveError`] instead of panicking.
+	#[inline]
+	pub fn try_reserve_exact(&mut self, len: usize) -> Result<(), TryReserveError> {
+		let cap = self.buf.len().checked_add(len).ok_or(TryReserveError::CapacityOverflow)?;
+
+		let layout = Layout::array::<(K, V)>(cap).map_err(|_| TryReserveError::CapacityOverflow)?;
+
+		self.buf.try_reserve_exact(len).map_err(|_| TryReserveError::AllocError { layout })
 	}
 
 	/// Shrinks the map to a specified length.
@@ -273,6 +371,13 @@ impl<K, V, A: Allocator> IdentityMap<K, V, A> {
 		self.buf.as_mut_slice()
 	}
 
+	/// Gets a sorted [`Slice`] view over the map's key-value pairs.
+	#[inline(always)]
+	#[must_use]
+	pub fn entries(&self) -> Slice<'_, K, V> {
+		Slice::new(self.as_slice())
+	}
+
 	#[allow(unused)]
 	#[inline(always)]
 	#[must_use]
@@ -313,7 +418,9 @@ where
 {
 	/// Inserts all key-value pairs from another map, overwriting duplicates.
 	///
-	/// The other map `other` will be completely cleared
+	/// The other map `other` will be completely cleared.
+	///
+	/// As both maps are already sorted by key, this is implemented as a single linear merge of the two buffers rather than by re-inserting one pair at a time, running in *O*(*n* + *m*) instead of *O*(*m* log *n*).
 	#[inline]
 	#[track_caller]
 	pub fn append(&mut self, other: &mut Self)
@@ -327,7 +434,147 @@ where
 			return;
 		}
 
-		self.extend(other.drain());
+		let alloc = self.buf.allocator().clone();
+		let mut merged = Vec::with_capacity_in(self.len() + other.len(), alloc);
+
+		{
+			let mut lhs = self.buf.drain(..);
+			let mut rhs = other.buf.drain(..);
+
+			let mut lhs_next = lhs.next();
+			let mut rhs_next = rhs.next();
+
+			loop {
+				match (lhs_next.take(), rhs_next.take()) {
+					(None, None) => break,
+
+					(Some(l), None) => {
+						merged.push(l);
+						merged.extend(lhs.by_ref());
+						break;
+					}
+
+					(None, Some(r)) => {
+						merged.push(r);
+						merged.extend(rhs.by_ref());
+						break;
+					}
+
+					// `other`'s value takes precedence on a
+					// shared key, matching `insert`'s over-
+					// write semantics; the superseded pair
+					// from `self` is simply dropped.
+					(Some(l), Some(r)) => match l.0.cmp(&r.0) {
+						Ordering::Less => {
+							merged.push(l);
+							rhs_next = Some(r);
+							lhs_next = lhs.next();
+						}
+
+						Ordering::Greater => {
+							merged.push(r);
+							lhs_next = Some(l);
+							rhs_next = rhs.next();
+						}
+
+						Ordering::Equal => {
+							merged.push(r);
+							lhs_next = lhs.next();
+							rhs_next = rhs.next();
+						}
+					}
+				}
+			}
+		}
+
+		self.buf = merged;
+	}
+
+	/// Splits the map into two at the given key.
+	///
+	/// Returns a newly-allocated map containing every pair whose key is greater than or equal to `key`.
+	/// `self` retains every pair whose key is strictly less than it.
+	///
+	/// As the map is already sorted by key, the split point is located with a single binary search, and the tail is moved in one linear pass, running in *O*(log *n* + *m*) where *m* is the size of the returned map.
+	#[inline]
+	#[track_caller]
+	pub fn split_off(&mut self, key: &K) -> Self
+	where
+		A: Clone,
+	{
+		let index = self.get_index(key).unwrap_or_else(|index| index);
+
+		let buf = self.buf.split_off(index);
+		Self { buf }
+	}
+
+	/// Builds a map from arbitrarily-ordered, possibly duplicate-keyed pairs.
+	///
+	/// `buf` is sorted by key and then compacted in a single pass, keeping the last-occurring value of each group of duplicate keys -- matching [`insert`](Self::insert)'s overwrite semantics.
+	/// This runs in *O*(*n* log *n*), against the *O*(*n*<sup>2</sup>) of inserting every pair one at a time.
+	#[inline]
+	fn from_unsorted(mut buf: Vec<(K, V), A>) -> Self {
+		buf.sort_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
+		Self::dedup_sorted(&mut buf);
+
+		Self { buf }
+	}
+
+	/// Compacts an already key-sorted buffer in place, keeping the last-occurring value of each group of duplicate keys.
+	#[inline]
+	fn dedup_sorted(buf: &mut Vec<(K, V), A>) {
+		if buf.is_empty() { return };
+
+		let mut write = 0x0;
+
+		for read in 0x1..buf.len() {
+			if buf[read].0 == buf[write].0 {
+				buf.swap(write, read);
+			} else {
+				write += 0x1;
+
+				if write != read {
+					buf.swap(write, read);
+				}
+			}
+		}
+
+		buf.truncate(write + 0x1);
+	}
+
+	/// Constructs a new identity map from an iterator of key-value pairs, without sorting them.
+	///
+	/// The caller must guarantee that `iter` yields pairs in strictly ascending order by key, with no duplicate keys, or the resulting map's binary-search-based methods (e.g. [`get`](Self::get), [`insert`](Self::insert), [`remove`](Self::remove)) will silently misbehave.
+	/// Unlike hashbrown's `insert_unique_unchecked`, misordered input here cannot cause undefined behaviour -- a broken sorted invariant only yields wrong lookups, never an out-of-bounds access -- so this is a safe (if misuse-prone) fast path rather than an `unsafe fn`.
+	#[inline]
+	pub fn from_sorted<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self
+	where
+		A: Default,
+	{
+		let mut this = Self::new_in(Default::default());
+
+		this.extend_sorted(iter);
+
+		this
+	}
+
+	/// Extends the map from an iterator of key-value pairs, without sorting them relative to each other or to the map's existing contents.
+	///
+	/// This is a fast path analogous to [`insert_sorted`](Self::insert_sorted): it skips both the sort and the binary search that [`extend`](Self::extend)/[`insert`](Self::insert) would otherwise perform, and simply appends.
+	/// The caller must guarantee that `iter` yields pairs in strictly ascending order by key, that every such key is strictly greater than the map's current [`last_key_value`](Self::last_key_value) (if any), and that no duplicate keys occur across the two -- violating this only breaks the sorted invariant that this map's binary-search-based methods rely upon, it cannot cause undefined behaviour.
+	#[inline(always)]
+	pub fn extend_sorted<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+		self.buf.extend(iter);
+	}
+
+	/// Inserts a new key-value pair into the map, without checking that the key is new or properly ordered.
+	///
+	/// This is a fast path, analogous to hashbrown's `insert_unique_unchecked`: it skips both the binary search and the shift that [`insert`](Self::insert) would otherwise perform, and simply appends.
+	/// The caller must guarantee that `key` is not already present in the map, and that it is strictly greater than the map's current [`last_key_value`](Self::last_key_value) (if any).
+	/// Unlike hashbrown's method, violating this invariant does not by itself cause undefined behaviour -- it only silently breaks the sorted invariant that this map's binary-search-based methods rely upon -- so, again unlike hashbrown's, this method is safe to call.
+	#[inline(always)]
+	pub fn insert_sorted(&mut self, key: K, value: V) {
+		self.buf.push((key, value));
 	}
 
 	/// Replaces a key-value pair.
@@ -379,6 +626,46 @@ where
 		None
 	}
 
+	/// Gets the specified key's corresponding entry in the map for in-place manipulation.
+	///
+	/// This allows for insert-or-update patterns -- such as the one implemented by [`insert`](Self::insert) itself -- to be expressed without performing a second lookup of the key.
+	#[inline]
+	pub fn entry(&mut self, key: K) -> Entry<'_, K, V, A> {
+		match self.get_index(&key) {
+			Ok(index)  => Entry::Occupied(OccupiedEntry::new(self, index)),
+			Err(index) => Entry::Vacant(VacantEntry::new(self, index, key)),
+		}
+	}
+
+	/// Inserts a new key-value pair into the map, without panicking.
+	///
+	/// This is the fallible counterpart to [`insert`](Self::insert).
+	///
+	/// # Errors
+	///
+	/// If the map did not already hold `key` as a key and could not grow its buffer to accommodate the `key` & `value` pair, then this method returns a [`TryInsertError`] -- carrying back the un-inserted key and value -- instead of panicking.
+	#[inline]
+	pub fn try_insert(&mut self, key: K, mut value: V) -> Result<Option<V>, TryInsertError<K, V>> {
+		let index = match self.get_index(&key) {
+			Ok(index) => {
+				let (_, other_value) = self.buf.get_mut(index).unwrap();
+
+				swap(other_value, &mut value);
+				return Ok(Some(value));
+			}
+
+			Err(index) => index,
+		};
+
+		if let Err(error) = self.try_reserve(0x1) {
+			return Err(TryInsertError { error, key, value });
+		}
+
+		self.buf.insert(index, (key, value));
+
+		Ok(None)
+	}
+
 	/// Removes the whole pair associated with the specific key.
 	///
 	/// The associated value is returned from this method.
@@ -451,6 +738,102 @@ where
 		})
 	}
 
+	/// Resolves a key range into a pair of buffer indices.
+	///
+	/// # Panics
+	///
+	/// Panics if the range's start bound lies strictly after its end bound, or if both bounds are equal and excluded -- matching the precondition [`BTreeMap::range`](alloc::collections::BTreeMap::range) imposes.
+	#[inline]
+	#[track_caller]
+	fn resolve_range<Q, R>(&self, range: &R) -> (usize, usize)
+	where
+		K: Borrow<Q>,
+		Q: Ord + ?Sized,
+		R: RangeBounds<Q>,
+	{
+		match (range.start_bound(), range.end_bound()) {
+			(Bound::Excluded(start), Bound::Excluded(end)) if start == end => {
+				panic!("range start and end are equal and excluded in IdentityMap");
+			}
+
+			(Bound::Included(start) | Bound::Excluded(start), Bound::Included(end) | Bound::Excluded(end)) if start > end => {
+				panic!("range start is greater than range end in IdentityMap");
+			}
+
+			_ => { }
+		}
+
+		let lo = match range.start_bound() {
+			Bound::Included(start) => self.buf.partition_point(|(key, _)| key.borrow() < start),
+			Bound::Excluded(start) => self.buf.partition_point(|(key, _)| key.borrow() <= start),
+			Bound::Unbounded       => 0x0,
+		};
+
+		let hi = match range.end_bound() {
+			Bound::Included(end) => self.buf.partition_point(|(key, _)| key.borrow() <= end),
+			Bound::Excluded(end) => self.buf.partition_point(|(key, _)| key.borrow() < end),
+			Bound::Unbounded     => self.buf.len(),
+		};
+
+		(lo, hi)
+	}
+
+	/// Gets an iterator of the key-value pairs whose keys fall within the specified range.
+	///
+	/// # Panics
+	///
+	/// Panics if `range`'s start bound lies strictly after its end bound, or if both bounds are equal and excluded.
+	#[inline]
+	#[track_caller]
+	pub fn range<Q, R>(&self, range: R) -> Range<'_, K, V>
+	where
+		K: Borrow<Q>,
+		Q: Ord + ?Sized,
+		R: RangeBounds<Q>,
+	{
+		let (lo, hi) = self.resolve_range(&range);
+
+		Range::new(&self.buf[lo..hi])
+	}
+
+	/// Gets a mutable iterator of the key-value pairs whose keys fall within the specified range.
+	///
+	/// # Panics
+	///
+	/// Panics if `range`'s start bound lies strictly after its end bound, or if both bounds are equal and excluded.
+	#[inline]
+	#[track_caller]
+	pub fn range_mut<Q, R>(&mut self, range: R) -> RangeMut<'_, K, V>
+	where
+		K: Borrow<Q>,
+		Q: Ord + ?Sized,
+		R: RangeBounds<Q>,
+	{
+		let (lo, hi) = self.resolve_range(&range);
+
+		RangeMut::new(&mut self.buf[lo..hi])
+	}
+
+	/// Drains the key-value pairs whose keys fall within the specified range.
+	///
+	/// The returned iterator yields pairs in ascending key order and, on drop, removes any pairs in the range that were not yet consumed.
+	///
+	/// # Panics
+	///
+	/// Panics if `range`'s start bound lies strictly after its end bound, or if both bounds are equal and excluded.
+	#[inline]
+	#[track_caller]
+	pub fn drain_range<Q, R>(&mut self, range: R) -> Drain<'_, K, V, A>
+	where
+		K: Borrow<Q>,
+		Q: Ord + ?Sized,
+		R: RangeBounds<Q>,
+	{
+		let (lo, hi) = self.resolve_range(&range);
+
+		Drain::new_range(self, lo..hi)
+	}
+
 	/// Borrows a key-value pair.
 	#[inline(always)]
 	#[must_use]
@@ -505,6 +888,75 @@ where
 		}
 	}
 
+	/// Mutably borrows the associated values of several keys at once.
+	///
+	/// Unlike repeated calls to [`get_mut`](Self::get_mut), this allows every returned reference to be held simultaneously, as the keys are first resolved to buffer indices and checked to be pairwise distinct before any reference is handed out.
+	///
+	/// # Panics
+	///
+	/// Panics if two or more of the given keys resolve to the same entry.
+	#[inline]
+	#[must_use]
+	#[track_caller]
+	pub fn get_disjoint_mut<Q, const N: usize>(&mut self, keys: [&Q; N]) -> [Option<&mut V>; N]
+	where
+		K: Borrow<Q>,
+		Q: Ord + ?Sized,
+	{
+		let indices = keys.map(|key| self.get_index(key).ok());
+
+		for i in 0x0..N {
+			if let Some(index) = indices[i] {
+				for j in 0x0..i {
+					assert!(indices[j] != Some(index), "key at index `{i}` aliases a previously requested key");
+				}
+			}
+		}
+
+		let ptr = self.buf.as_mut_ptr();
+
+		indices.map(|index| index.map(|index| {
+			// SAFETY: `index` was resolved via `get_index` and
+			// is therefore in bounds. The loop above guaran-
+			// tees that no two resolved indices alias the
+			// same element.
+			unsafe { &mut (*ptr.add(index)).1 }
+		}))
+	}
+
+	/// Mutably borrows several disjoint values at once, addressed directly by buffer index.
+	///
+	/// This is the index-based counterpart to [`get_disjoint_mut`](Self::get_disjoint_mut), for callers who already know the positions of their entries within the sorted buffer.
+	/// An out-of-bounds index simply resolves to `None`, matching [`Slice::get_index`]'s indexing.
+	///
+	/// # Panics
+	///
+	/// Panics if `indices` contains the same index more than once.
+	#[inline]
+	#[must_use]
+	#[track_caller]
+	pub fn get_disjoint_indices_mut<const N: usize>(&mut self, indices: [usize; N]) -> [Option<&mut V>; N] {
+		for i in 0x0..N {
+			for j in 0x0..i {
+				assert!(indices[i] != indices[j], "index `{}` was requested more than once", indices[i]);
+			}
+		}
+
+		let len = self.buf.len();
+		let ptr = self.buf.as_mut_ptr();
+
+		indices.map(|index| {
+			if index < len {
+				// SAFETY: `index` is in bounds, and the asser-
+				// tions above guarantee that no two requested
+				// indices alias the same element.
+				Some(unsafe { &mut (*ptr.add(index)).1 })
+			} else {
+				None
+			}
+		})
+	}
+
 	/// Borrows the first key-value pair.
 	#[inline(always)]
 	#[must_use]
@@ -566,6 +1018,21 @@ where
 	fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
 		let iter = iter.into_iter();
 
+		// If the map starts out empty, building via the sort-
+		// and-dedup bulk path is asymptotically faster than
+		// inserting pair by pair, as no element-shifting binary
+		// search is needed per pair.
+
+		if self.is_empty() {
+			self.buf.reserve(iter.size_hint().0);
+			self.buf.extend(iter);
+
+			self.buf.sort_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
+			Self::dedup_sorted(&mut self.buf);
+
+			return;
+		}
+
 		self.reserve(iter.size_hint().0);
 
 		for (key, value) in iter {
@@ -594,13 +1061,10 @@ where
 	fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
 		let iter = iter.into_iter();
 
-		let mut this = Self::with_capacity_in(iter.size_hint().0, Default::default());
-
-		for (key, value) in iter {
-			this.insert(key, value);
-		}
+		let mut buf = Vec::with_capacity_in(iter.size_hint().0, Default::default());
+		buf.extend(iter);
 
-		this
+		Self::from_unsorted(buf)
 	}
 }
 
@@ -2,6 +2,7 @@
 
 use crate::identity_map::IdentityMap;
 
+use alloc::vec::Vec as StdVec;
 use allocator_api2::alloc::Allocator;
 use core::any::type_name;
 use core::fmt::{self, Formatter};
@@ -42,16 +43,19 @@ where
 
 	#[inline]
 	fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
-		let alloc = Default::default();
-		let cap   = map.size_hint().unwrap_or_default();
+		let cap = map.size_hint().unwrap_or_default();
 
-		let mut this = IdentityMap::with_capacity_in(cap, alloc);
+		let mut buf = StdVec::with_capacity(cap);
 
-		while let Some((key, value)) = map.next_entry()? {
-			this.insert(key, value);
+		while let Some(entry) = map.next_entry()? {
+			buf.push(entry);
 		}
 
-		Ok(this)
+		// Building from the collected entries in one pass
+		// sorts and dedups once, rather than doing a binary
+		// search and shift for every entry.
+
+		Ok(IdentityMap::from_iter(buf))
 	}
 }
 
@@ -31,6 +31,7 @@ use crate::IdentityMap;
 use alloc::vec::Vec;
 use bincode::{deserialize_from, serialize_into};
 use core::sync::atomic::{AtomicU8, Ordering};
+use serde::{Deserialize, Serialize};
 
 #[allow(clippy::len_zero)]
 #[test]
@@ -169,6 +170,35 @@ fn test_identity_map_drain() {
 	assert!(map.is_empty());
 }
 
+#[test]
+fn test_identity_map_drain_range() {
+	let mut map = IdentityMap::<u8, u8>::from([
+		(0x00, 0x00),
+		(0x10, 0x01),
+		(0x20, 0x02),
+		(0x30, 0x03),
+		(0x40, 0x04),
+	]);
+
+	let drained: Vec<_> = map.drain_range(0x10..0x30).collect();
+
+	assert_eq!(drained, [(0x10, 0x01), (0x20, 0x02)]);
+
+	assert_eq!(map.len(), 0x3);
+	assert_eq!(map.get(&0x00), Some(&0x00));
+	assert_eq!(map.get(&0x30), Some(&0x03));
+	assert_eq!(map.get(&0x40), Some(&0x04));
+
+	// Dropping the iterator early still removes the whole
+	// requested range and leaves the rest of the map intact.
+
+	drop(map.drain_range(0x30..=0x30));
+
+	assert_eq!(map.len(), 0x2);
+	assert_eq!(map.get(&0x00), Some(&0x00));
+	assert_eq!(map.get(&0x40), Some(&0x04));
+}
+
 #[test]
 fn test_identity_map_drop() {
 	static COUNTER: AtomicU8 = AtomicU8::new(0x0);
@@ -211,6 +241,51 @@ fn test_identity_map_from_array() {
 	assert_eq!(map1.get(&false), Some(&true));
 }
 
+#[test]
+fn test_identity_map_try_with_capacity() {
+	let map = IdentityMap::<u8, u8>::try_with_capacity(0x10).unwrap();
+
+	assert!(map.capacity() >= 0x10);
+	assert!(map.len() == 0x0);
+	assert!(map.is_empty());
+
+	assert!(IdentityMap::<u8, u8>::try_with_capacity(usize::MAX).is_err());
+}
+
+#[test]
+fn test_identity_map_reserve_exact() {
+	let mut map = IdentityMap::<u8, u8>::new();
+
+	map.reserve_exact(0x10);
+
+	assert!(map.capacity() >= 0x10);
+
+	assert!(map.try_reserve_exact(usize::MAX).is_err());
+}
+
+#[test]
+fn test_identity_map_shrink() {
+	let mut map = IdentityMap::<u8, u8>::with_capacity(0x100);
+
+	map.insert(0x00, 0x00);
+	map.insert(0x10, 0x01);
+
+	assert!(map.capacity() >= 0x100);
+
+	map.shrink_to(0x20);
+
+	assert!(map.capacity() >= 0x20);
+	assert_eq!(map.len(), 0x2);
+	assert_eq!(map.get(&0x00), Some(&0x00));
+	assert_eq!(map.get(&0x10), Some(&0x01));
+
+	map.shrink_to_fit();
+
+	assert_eq!(map.capacity(), map.len());
+	assert_eq!(map.get(&0x00), Some(&0x00));
+	assert_eq!(map.get(&0x10), Some(&0x01));
+}
+
 #[test]
 fn test_identity_map_iter() {
 	let mut map = IdentityMap::<u8, u8>::from([
@@ -292,6 +367,346 @@ fn test_identity_map_iter() {
 	assert_eq!(iter.next(), None);
 }
 
+#[test]
+fn test_identity_map_range() {
+	let mut map = IdentityMap::<u8, u8>::from([
+		(0x00, 0x00),
+		(0x10, 0x10),
+		(0x20, 0x20),
+		(0x30, 0x30),
+		(0x40, 0x40),
+	]);
+
+	let mut iter = map.range(0x10..0x30);
+
+	assert_eq!(iter.next(), Some((&0x10, &0x10)));
+	assert_eq!(iter.next(), Some((&0x20, &0x20)));
+	assert_eq!(iter.next(), None);
+
+	let mut iter = map.range(0x10..=0x30);
+
+	assert_eq!(iter.next(), Some((&0x10, &0x10)));
+	assert_eq!(iter.next(), Some((&0x20, &0x20)));
+	assert_eq!(iter.next(), Some((&0x30, &0x30)));
+	assert_eq!(iter.next(), None);
+
+	let mut iter = map.range(..);
+
+	assert_eq!(iter.next(), Some((&0x00, &0x00)));
+	assert_eq!(iter.next(), Some((&0x10, &0x10)));
+	assert_eq!(iter.next(), Some((&0x20, &0x20)));
+	assert_eq!(iter.next(), Some((&0x30, &0x30)));
+	assert_eq!(iter.next(), Some((&0x40, &0x40)));
+	assert_eq!(iter.next(), None);
+
+	for (_, value) in map.range_mut(0x10..=0x20) {
+		*value += 0x1;
+	}
+
+	assert_eq!(map.get(&0x00), Some(&0x00));
+	assert_eq!(map.get(&0x10), Some(&0x11));
+	assert_eq!(map.get(&0x20), Some(&0x21));
+	assert_eq!(map.get(&0x30), Some(&0x30));
+}
+
+#[test]
+fn test_identity_map_entries() {
+	let map = IdentityMap::<u8, u8>::from([
+		(0x00, 0x00),
+		(0x10, 0x01),
+		(0x20, 0x02),
+		(0x30, 0x03),
+	]);
+
+	let entries = map.entries();
+
+	assert_eq!(entries.len(), 0x4);
+	assert!(!entries.is_empty());
+	assert_eq!(entries.first(), Some((&0x00, &0x00)));
+	assert_eq!(entries.last(), Some((&0x30, &0x03)));
+	assert_eq!(entries.get_index(0x2), Some((&0x20, &0x02)));
+	assert_eq!(entries.get_index(0xFF), None);
+	assert_eq!(entries.binary_search(&0x20), Ok(0x2));
+	assert_eq!(entries.binary_search(&0x25), Err(0x3));
+	assert_eq!(entries.partition_point(|(key, _)| *key < 0x20), 0x2);
+
+	let (lo, hi) = entries.split_at(0x2);
+
+	assert_eq!(lo.len(), 0x2);
+	assert_eq!(lo.last(), Some((&0x10, &0x01)));
+	assert_eq!(hi.len(), 0x2);
+	assert_eq!(hi.first(), Some((&0x20, &0x02)));
+
+	let empty = IdentityMap::<u8, u8>::new();
+
+	assert!(empty.entries().is_empty());
+	assert_eq!(empty.entries().first(), None);
+}
+
+#[test]
+#[should_panic]
+fn test_identity_map_range_inverted_panics() {
+	let map = IdentityMap::<u8, u8>::from([(0x00, 0x00), (0x10, 0x10)]);
+
+	let _ = map.range(0x30..0x10);
+}
+
+#[test]
+fn test_identity_map_append_split_off() {
+	let mut lhs = IdentityMap::<u8, u8>::from([
+		(0x00, 0x00),
+		(0x10, 0x10),
+		(0x30, 0x30),
+	]);
+
+	let mut rhs = IdentityMap::<u8, u8>::from([
+		(0x10, 0x11),
+		(0x20, 0x20),
+	]);
+
+	lhs.append(&mut rhs);
+
+	assert!(rhs.is_empty());
+
+	assert_eq!(lhs.len(), 0x4);
+	assert_eq!(lhs.get(&0x00), Some(&0x00));
+	assert_eq!(lhs.get(&0x10), Some(&0x11));
+	assert_eq!(lhs.get(&0x20), Some(&0x20));
+	assert_eq!(lhs.get(&0x30), Some(&0x30));
+
+	let tail = lhs.split_off(&0x10);
+
+	assert_eq!(lhs.len(), 0x1);
+	assert_eq!(lhs.get(&0x00), Some(&0x00));
+
+	assert_eq!(tail.len(), 0x3);
+	assert_eq!(tail.get(&0x10), Some(&0x11));
+	assert_eq!(tail.get(&0x20), Some(&0x20));
+	assert_eq!(tail.get(&0x30), Some(&0x30));
+
+	// Splitting at a key greater than any present key yields
+	// an empty tail.
+
+	let mut lhs = tail;
+	let empty = lhs.split_off(&0xFF);
+
+	assert!(empty.is_empty());
+	assert_eq!(lhs.len(), 0x3);
+}
+
+#[test]
+fn test_identity_map_extract_if() {
+	let mut map = IdentityMap::<u8, u8>::from([
+		(0x00, 0x00),
+		(0x10, 0x01),
+		(0x20, 0x02),
+		(0x30, 0x03),
+		(0x40, 0x04),
+	]);
+
+	let extracted: Vec<_> = map.extract_if(|_, value| *value % 0x2 == 0x0).collect();
+
+	assert_eq!(extracted, [(0x00, 0x00), (0x20, 0x02), (0x40, 0x04)]);
+
+	assert_eq!(map.len(), 0x2);
+	assert_eq!(map.get(&0x10), Some(&0x01));
+	assert_eq!(map.get(&0x30), Some(&0x03));
+
+	// Dropping the iterator early still leaves the map fully
+	// compacted.
+
+	let mut map = IdentityMap::<u8, u8>::from([
+		(0x00, 0x00),
+		(0x10, 0x01),
+		(0x20, 0x02),
+	]);
+
+	drop(map.extract_if(|key, _| *key == 0x10));
+
+	assert_eq!(map.len(), 0x2);
+	assert_eq!(map.get(&0x00), Some(&0x00));
+	assert_eq!(map.get(&0x20), Some(&0x02));
+
+	// A predicate that never matches extracts nothing and
+	// leaves the map untouched.
+
+	assert_eq!(map.extract_if(|_, _| false).count(), 0x0);
+	assert_eq!(map.len(), 0x2);
+
+	// The predicate observes a mutable reference to the
+	// value, so it may be updated before the decision is
+	// made.
+
+	let mut map = IdentityMap::<u8, u8>::from([(0x00, 0x01), (0x10, 0x02)]);
+
+	let extracted: Vec<_> = map.extract_if(|_, value| {
+		*value *= 0x2;
+		*value >= 0x4
+	}).collect();
+
+	assert_eq!(extracted, [(0x10, 0x04)]);
+	assert_eq!(map.get(&0x00), Some(&0x02));
+}
+
+#[test]
+fn test_identity_map_retain() {
+	let mut map = IdentityMap::<u8, u8>::from([
+		(0x00, 0x00),
+		(0x10, 0x01),
+		(0x20, 0x02),
+		(0x30, 0x03),
+		(0x40, 0x04),
+	]);
+
+	map.retain(|_, value| *value % 0x2 != 0x0);
+
+	assert_eq!(map.len(), 0x2);
+	assert_eq!(map.get(&0x10), Some(&0x01));
+	assert_eq!(map.get(&0x30), Some(&0x03));
+
+	map.retain(|_, _| false);
+
+	assert!(map.is_empty());
+}
+
+#[test]
+fn test_identity_map_entry() {
+	let mut map = IdentityMap::<u8, u8>::from([(0x10, 0x01)]);
+
+	*map.entry(0x10).or_insert(0x05) += 0x1;
+
+	assert_eq!(map.get(&0x10), Some(&0x02));
+
+	*map.entry(0x20).or_insert(0x05) += 0x1;
+
+	assert_eq!(map.get(&0x20), Some(&0x06));
+
+	map.entry(0x10).and_modify(|value| *value *= 0x2).or_insert(0x00);
+
+	assert_eq!(map.get(&0x10), Some(&0x04));
+
+	map.entry(0x30).and_modify(|value| *value *= 0x2).or_insert(0x09);
+
+	assert_eq!(map.get(&0x30), Some(&0x09));
+
+	let value = map.entry(0x40).or_insert_with_key(|key| key * 0x2);
+
+	assert_eq!(*value, 0x80);
+
+	match map.entry(0x10) {
+		crate::identity_map::Entry::Occupied(entry) => {
+			assert_eq!(entry.key(), &0x10);
+			assert_eq!(entry.get(), &0x04);
+			assert_eq!(entry.remove(), 0x04);
+		}
+
+		crate::identity_map::Entry::Vacant(_) => panic!("key should be occupied"),
+	}
+
+	assert_eq!(map.get(&0x10), None);
+	assert_eq!(map.len(), 0x3);
+
+	let value = map.entry(0x50).or_insert_with(|| 0x0A);
+
+	assert_eq!(*value, 0x0A);
+	assert_eq!(map.get(&0x50), Some(&0x0A));
+
+	let mut map: IdentityMap<u8, u8> = IdentityMap::new();
+
+	assert_eq!(*map.entry(0x01).or_default(), 0x00);
+
+	match map.entry(0x01) {
+		crate::identity_map::Entry::Occupied(mut entry) => {
+			assert_eq!(entry.insert(0x0B), 0x00);
+			assert_eq!(entry.get(), &0x0B);
+		}
+
+		crate::identity_map::Entry::Vacant(_) => panic!("key should be occupied"),
+	}
+
+	match map.entry(0x02) {
+		crate::identity_map::Entry::Occupied(_) => panic!("key should be vacant"),
+		crate::identity_map::Entry::Vacant(entry) => assert_eq!(entry.into_key(), 0x02),
+	}
+
+	match map.entry(0x01) {
+		crate::identity_map::Entry::Occupied(entry) => assert_eq!(entry.remove_entry(), (0x01, 0x0B)),
+		crate::identity_map::Entry::Vacant(_)       => panic!("key should be occupied"),
+	}
+
+	assert_eq!(map.get(&0x01), None);
+}
+
+#[test]
+fn test_identity_map_sorted() {
+	let mut map = IdentityMap::<u8, u8>::from_sorted([
+		(0x00, 0x00),
+		(0x10, 0x01),
+		(0x20, 0x02),
+	]);
+
+	assert_eq!(map.len(), 0x3);
+	assert_eq!(map.get(&0x10), Some(&0x01));
+
+	map.extend_sorted([(0x30, 0x03), (0x40, 0x04)]);
+
+	assert_eq!(map.len(), 0x5);
+	assert_eq!(map.get(&0x00), Some(&0x00));
+	assert_eq!(map.get(&0x30), Some(&0x03));
+	assert_eq!(map.get(&0x40), Some(&0x04));
+
+	map.insert_sorted(0x50, 0x05);
+
+	assert_eq!(map.len(), 0x6);
+	assert_eq!(map.get(&0x50), Some(&0x05));
+	assert_eq!(map.last_key_value(), Some((&0x50, &0x05)));
+}
+
+#[test]
+fn test_identity_map_get_disjoint_mut() {
+	let mut map = IdentityMap::<u8, u8>::new();
+
+	map.insert(0x00, 0x00);
+	map.insert(0x10, 0x01);
+	map.insert(0x20, 0x02);
+	map.insert(0x30, 0x03);
+
+	let [a, b] = map.get_disjoint_mut([&0x10, &0x30]);
+
+	assert_eq!(a, Some(&mut 0x01));
+	assert_eq!(b, Some(&mut 0x03));
+
+	*a.unwrap() += 0x1;
+	*b.unwrap() += 0x1;
+
+	assert_eq!(map.get(&0x10), Some(&0x02));
+	assert_eq!(map.get(&0x30), Some(&0x04));
+
+	let [a, b, c] = map.get_disjoint_mut([&0x10, &0x40, &0x30]);
+
+	assert_eq!(a, Some(&mut 0x02));
+	assert_eq!(b, None);
+	assert_eq!(c, Some(&mut 0x04));
+
+	let [a, b] = map.get_disjoint_indices_mut([0x1, 0x3]);
+
+	assert_eq!(a, Some(&mut 0x02));
+	assert_eq!(b, Some(&mut 0x04));
+
+	let [a, b] = map.get_disjoint_indices_mut([0x1, 0x9]);
+
+	assert_eq!(a, Some(&mut 0x02));
+	assert_eq!(b, None);
+}
+
+#[test]
+#[should_panic]
+fn test_identity_map_get_disjoint_mut_aliased_panics() {
+	let mut map = IdentityMap::<u8, u8>::from([(0x10, 0x01), (0x30, 0x03)]);
+
+	let _ = map.get_disjoint_mut([&0x10, &0x10]);
+}
+
 #[test]
 fn test_identity_set_serialise_deserialise() {
 	let input = IdentityMap::<char, [u8; 0x2]>::from([
@@ -311,3 +726,24 @@ fn test_identity_set_serialise_deserialise() {
 
 	assert_eq!(output, input);
 }
+
+#[test]
+fn test_identity_map_serialise_deserialise_seq() {
+	#[derive(Deserialize, Serialize)]
+	struct Wrapper {
+		#[serde(with = "crate::serde_seq")]
+		map: IdentityMap<u32, u8>,
+	}
+
+	let input = Wrapper {
+		map: IdentityMap::from([(0x10, 0x01), (0x00, 0x00), (0x20, 0x02)]),
+	};
+
+	let mut buf = Vec::new();
+
+	serialize_into(&mut buf, &input).unwrap();
+
+	let output: Wrapper = deserialize_from(&*buf).unwrap();
+
+	assert_eq!(output.map, input.map);
+}
@@ -3,6 +3,8 @@
 //! The [`IdentityMap`] type and associated facilities.
 
 mod drain;
+mod entry;
+mod extract_if;
 mod identity_map;
 mod into_iter;
 mod into_keys;
@@ -10,10 +12,18 @@ mod into_values;
 mod iter;
 mod iter_mut;
 mod keys;
+mod range;
+mod range_mut;
+mod slice;
 mod values;
 mod values_mut;
 
+#[cfg(feature = "rayon")]
+mod rayon;
+
 pub use drain::Drain;
+pub use entry::{Entry, OccupiedEntry, VacantEntry};
+pub use extract_if::ExtractIf;
 pub use identity_map::IdentityMap;
 pub use into_iter::IntoIter;
 pub use into_keys::IntoKeys;
@@ -21,5 +31,11 @@ pub use into_values::IntoValues;
 pub use iter::Iter;
 pub use iter_mut::IterMut;
 pub use keys::Keys;
+pub use range::Range;
+pub use range_mut::RangeMut;
+pub use slice::Slice;
 pub use values::Values;
 pub use values_mut::ValuesMut;
+
+#[cfg(feature = "rayon")]
+pub use rayon::{ParIter, ParIterMut, ParKeys, ParValues, ParValuesMut};
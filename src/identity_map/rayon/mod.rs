@@ -0,0 +1,475 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+//! Optional [`rayon`] parallel iteration support.
+//!
+//! This module is only available when the `rayon` feature is enabled.
+
+use crate::identity_map::IdentityMap;
+
+use alloc::vec::Vec as StdVec;
+use allocator_api2::alloc::Allocator;
+use core::iter::Map as IterMap;
+use core::slice::{Iter as SliceIter, IterMut as SliceIterMut};
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{
+	FromParallelIterator,
+	IndexedParallelIterator,
+	IntoParallelIterator,
+	Map as ParMap,
+	ParallelExtend,
+	ParallelIterator,
+};
+use rayon::vec::IntoIter as VecIntoIter;
+
+#[inline(always)]
+fn pair_ref<K, V>(pair: &(K, V)) -> (&K, &V) {
+	(&pair.0, &pair.1)
+}
+
+#[inline(always)]
+fn pair_ref_mut<K, V>(pair: &mut (K, V)) -> (&K, &mut V) {
+	(&pair.0, &mut pair.1)
+}
+
+#[inline(always)]
+fn pair_key<'a, K, V>(pair: (&'a K, &'a V)) -> &'a K {
+	pair.0
+}
+
+#[inline(always)]
+fn pair_value<'a, K, V>(pair: (&'a K, &'a V)) -> &'a V {
+	pair.1
+}
+
+#[inline(always)]
+fn pair_value_mut<'a, K, V>(pair: (&'a K, &'a mut V)) -> &'a mut V {
+	pair.1
+}
+
+/// A parallel iterator of the key-value pairs contained in an [`IdentityMap`].
+///
+/// This is constructed by the [`par_iter`](IdentityMap::par_iter) method on [`IdentityMap`].
+///
+/// As the map's backing buffer is already a single contiguous, sorted slice, this iterator's [`Producer`] just `split_at`s that slice -- no custom raw-table splitting (as e.g. `hashbrown` requires) is needed.
+#[must_use]
+pub struct ParIter<'a, K, V> {
+	slice: &'a [(K, V)],
+}
+
+impl<'a, K, V> ParIter<'a, K, V> {
+	#[inline(always)]
+	pub(crate) fn new(slice: &'a [(K, V)]) -> Self {
+		Self { slice }
+	}
+}
+
+/// A mutable parallel iterator of the key-value pairs contained in an [`IdentityMap`].
+///
+/// This is constructed by the [`par_iter_mut`](IdentityMap::par_iter_mut) method on [`IdentityMap`].
+#[must_use]
+pub struct ParIterMut<'a, K, V> {
+	slice: &'a mut [(K, V)],
+}
+
+impl<'a, K, V> ParIterMut<'a, K, V> {
+	#[inline(always)]
+	pub(crate) fn new(slice: &'a mut [(K, V)]) -> Self {
+		Self { slice }
+	}
+}
+
+struct IterProducer<'a, K, V> {
+	slice: &'a [(K, V)],
+}
+
+impl<'a, K: Sync, V: Sync> Producer for IterProducer<'a, K, V> {
+	type IntoIter = IterMap<SliceIter<'a, (K, V)>, fn(&'a (K, V)) -> (&'a K, &'a V)>;
+	type Item = (&'a K, &'a V);
+
+	#[inline(always)]
+	fn into_iter(self) -> Self::IntoIter {
+		self.slice.iter().map(pair_ref)
+	}
+
+	#[inline(always)]
+	fn split_at(self, index: usize) -> (Self, Self) {
+		let (lhs, rhs) = self.slice.split_at(index);
+
+		(IterProducer { slice: lhs }, IterProducer { slice: rhs })
+	}
+}
+
+struct IterMutProducer<'a, K, V> {
+	slice: &'a mut [(K, V)],
+}
+
+impl<'a, K: Sync + Send, V: Send> Producer for IterMutProducer<'a, K, V> {
+	type IntoIter = IterMap<SliceIterMut<'a, (K, V)>, fn(&'a mut (K, V)) -> (&'a K, &'a mut V)>;
+	type Item = (&'a K, &'a mut V);
+
+	#[inline(always)]
+	fn into_iter(self) -> Self::IntoIter {
+		self.slice.iter_mut().map(pair_ref_mut)
+	}
+
+	#[inline(always)]
+	fn split_at(self, index: usize) -> (Self, Self) {
+		let (lhs, rhs) = self.slice.split_at_mut(index);
+
+		(IterMutProducer { slice: lhs }, IterMutProducer { slice: rhs })
+	}
+}
+
+impl<'a, K: Sync, V: Sync> ParallelIterator for ParIter<'a, K, V> {
+	type Item = (&'a K, &'a V);
+
+	#[inline]
+	fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+		bridge(self, consumer)
+	}
+
+	#[inline(always)]
+	fn opt_len(&self) -> Option<usize> {
+		Some(self.slice.len())
+	}
+}
+
+impl<K: Sync, V: Sync> IndexedParallelIterator for ParIter<'_, K, V> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.slice.len()
+	}
+
+	#[inline]
+	fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+		bridge(self, consumer)
+	}
+
+	#[inline]
+	fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+		callback.callback(IterProducer { slice: self.slice })
+	}
+}
+
+impl<'a, K: Sync + Send, V: Send> ParallelIterator for ParIterMut<'a, K, V> {
+	type Item = (&'a K, &'a mut V);
+
+	#[inline]
+	fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+		bridge(self, consumer)
+	}
+
+	#[inline(always)]
+	fn opt_len(&self) -> Option<usize> {
+		Some(self.slice.len())
+	}
+}
+
+impl<K: Sync + Send, V: Send> IndexedParallelIterator for ParIterMut<'_, K, V> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.slice.len()
+	}
+
+	#[inline]
+	fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+		bridge(self, consumer)
+	}
+
+	#[inline]
+	fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+		callback.callback(IterMutProducer { slice: self.slice })
+	}
+}
+
+/// A parallel iterator of the keys contained in an [`IdentityMap`].
+///
+/// This is constructed by the [`par_keys`](IdentityMap::par_keys) method on [`IdentityMap`].
+#[must_use]
+pub struct ParKeys<'a, K, V> {
+	iter: ParMap<ParIter<'a, K, V>, fn((&'a K, &'a V)) -> &'a K>,
+}
+
+impl<'a, K: Sync, V: Sync> ParKeys<'a, K, V> {
+	#[inline(always)]
+	pub(crate) fn new(slice: &'a [(K, V)]) -> Self {
+		let iter = ParIter::new(slice).map(pair_key as fn((&'a K, &'a V)) -> &'a K);
+
+		Self { iter }
+	}
+}
+
+impl<'a, K: Sync, V: Sync> ParallelIterator for ParKeys<'a, K, V> {
+	type Item = &'a K;
+
+	#[inline]
+	fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+		self.iter.drive_unindexed(consumer)
+	}
+
+	#[inline(always)]
+	fn opt_len(&self) -> Option<usize> {
+		self.iter.opt_len()
+	}
+}
+
+impl<K: Sync, V: Sync> IndexedParallelIterator for ParKeys<'_, K, V> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.iter.len()
+	}
+
+	#[inline]
+	fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+		self.iter.drive(consumer)
+	}
+
+	#[inline]
+	fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+		self.iter.with_producer(callback)
+	}
+}
+
+/// A parallel iterator of the values contained in an [`IdentityMap`].
+///
+/// This is constructed by the [`par_values`](IdentityMap::par_values) method on [`IdentityMap`].
+#[must_use]
+pub struct ParValues<'a, K, V> {
+	iter: ParMap<ParIter<'a, K, V>, fn((&'a K, &'a V)) -> &'a V>,
+}
+
+impl<'a, K: Sync, V: Sync> ParValues<'a, K, V> {
+	#[inline(always)]
+	pub(crate) fn new(slice: &'a [(K, V)]) -> Self {
+		let iter = ParIter::new(slice).map(pair_value as fn((&'a K, &'a V)) -> &'a V);
+
+		Self { iter }
+	}
+}
+
+impl<'a, K: Sync, V: Sync> ParallelIterator for ParValues<'a, K, V> {
+	type Item = &'a V;
+
+	#[inline]
+	fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+		self.iter.drive_unindexed(consumer)
+	}
+
+	#[inline(always)]
+	fn opt_len(&self) -> Option<usize> {
+		self.iter.opt_len()
+	}
+}
+
+impl<K: Sync, V: Sync> IndexedParallelIterator for ParValues<'_, K, V> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.iter.len()
+	}
+
+	#[inline]
+	fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+		self.iter.drive(consumer)
+	}
+
+	#[inline]
+	fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+		self.iter.with_producer(callback)
+	}
+}
+
+/// A mutable parallel iterator of the values contained in an [`IdentityMap`].
+///
+/// This is constructed by the [`par_values_mut`](IdentityMap::par_values_mut) method on [`IdentityMap`].
+#[must_use]
+pub struct ParValuesMut<'a, K, V> {
+	iter: ParMap<ParIterMut<'a, K, V>, fn((&'a K, &'a mut V)) -> &'a mut V>,
+}
+
+impl<'a, K: Sync + Send, V: Send> ParValuesMut<'a, K, V> {
+	#[inline(always)]
+	pub(crate) fn new(slice: &'a mut [(K, V)]) -> Self {
+		let iter = ParIterMut::new(slice).map(pair_value_mut as fn((&'a K, &'a mut V)) -> &'a mut V);
+
+		Self { iter }
+	}
+}
+
+impl<'a, K: Sync + Send, V: Send> ParallelIterator for ParValuesMut<'a, K, V> {
+	type Item = &'a mut V;
+
+	#[inline]
+	fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+		self.iter.drive_unindexed(consumer)
+	}
+
+	#[inline(always)]
+	fn opt_len(&self) -> Option<usize> {
+		self.iter.opt_len()
+	}
+}
+
+impl<K: Sync + Send, V: Send> IndexedParallelIterator for ParValuesMut<'_, K, V> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.iter.len()
+	}
+
+	#[inline]
+	fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+		self.iter.drive(consumer)
+	}
+
+	#[inline]
+	fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+		self.iter.with_producer(callback)
+	}
+}
+
+impl<K, V, A: Allocator> IdentityMap<K, V, A> {
+	/// Gets a parallel iterator of the contained key-value pairs.
+	///
+	/// As the backing buffer is a single contiguous, sorted slice, this splits directly on it, giving a cheap, balanced divide-and-conquer with no extra allocation.
+	#[inline]
+	pub fn par_iter(&self) -> ParIter<'_, K, V>
+	where
+		K: Sync,
+		V: Sync,
+	{
+		ParIter::new(self.as_slice())
+	}
+
+	/// Gets a mutable parallel iterator of the contained key-value pairs.
+	#[inline]
+	pub fn par_iter_mut(&mut self) -> ParIterMut<'_, K, V>
+	where
+		K: Sync + Send,
+		V: Send,
+	{
+		ParIterMut::new(self.as_mut_slice())
+	}
+
+	/// Gets a parallel iterator of the contained keys.
+	#[inline]
+	pub fn par_keys(&self) -> ParKeys<'_, K, V>
+	where
+		K: Sync,
+		V: Sync,
+	{
+		ParKeys::new(self.as_slice())
+	}
+
+	/// Gets a parallel iterator of the contained values.
+	#[inline]
+	pub fn par_values(&self) -> ParValues<'_, K, V>
+	where
+		K: Sync,
+		V: Sync,
+	{
+		ParValues::new(self.as_slice())
+	}
+
+	/// Gets a mutable parallel iterator of the contained values.
+	#[inline]
+	pub fn par_values_mut(&mut self) -> ParValuesMut<'_, K, V>
+	where
+		K: Sync + Send,
+		V: Send,
+	{
+		ParValuesMut::new(self.as_mut_slice())
+	}
+
+	/// Drains the map in parallel, returning every key-value pair.
+	///
+	/// As `rayon` has no blanket parallel-iterator support for [`allocator_api2`]'s [`Vec`](allocator_api2::vec::Vec), the pairs are first moved into a standard, globally-allocated [`Vec`](alloc::vec::Vec).
+	#[inline]
+	pub fn par_drain(&mut self) -> VecIntoIter<(K, V)>
+	where
+		K: Send,
+		V: Send,
+	{
+		let buf = StdVec::from_iter(self.as_mut_vec().drain(..));
+		buf.into_par_iter()
+	}
+}
+
+impl<K, V, A> IdentityMap<K, V, A>
+where
+	K: Ord + Send,
+	V: Send,
+	A: Allocator,
+{
+	/// Extends the map from a parallel iterator of key-value pairs.
+	///
+	/// The incoming pairs are gathered in parallel; merging them into the map's sorted buffer then reuses the same serial bulk-construction path as [`Extend::extend`](Extend::extend).
+	#[inline]
+	pub fn par_extend<I: IntoParallelIterator<Item = (K, V)>>(&mut self, iter: I) {
+		let pairs: StdVec<_> = iter.into_par_iter().collect();
+		self.extend(pairs);
+	}
+}
+
+impl<K, V, A: Allocator> IntoParallelIterator for IdentityMap<K, V, A>
+where
+	K: Send,
+	V: Send,
+{
+	type Item = (K, V);
+	type Iter = VecIntoIter<(K, V)>;
+
+	/// As `rayon` has no blanket parallel-iterator support for [`allocator_api2`]'s [`Vec`](allocator_api2::vec::Vec), the pairs are first moved into a standard, globally-allocated [`Vec`](alloc::vec::Vec).
+	#[inline]
+	fn into_par_iter(self) -> Self::Iter {
+		let buf = StdVec::from_iter(self.into_vec());
+		buf.into_par_iter()
+	}
+}
+
+impl<'a, K, V, A: Allocator> IntoParallelIterator for &'a IdentityMap<K, V, A>
+where
+	K: Sync,
+	V: Sync,
+{
+	type Item = (&'a K, &'a V);
+	type Iter = ParIter<'a, K, V>;
+
+	#[inline(always)]
+	fn into_par_iter(self) -> Self::Iter {
+		self.par_iter()
+	}
+}
+
+impl<'a, K, V, A: Allocator> IntoParallelIterator for &'a mut IdentityMap<K, V, A>
+where
+	K: Sync + Send,
+	V: Send,
+{
+	type Item = (&'a K, &'a mut V);
+	type Iter = ParIterMut<'a, K, V>;
+
+	#[inline(always)]
+	fn into_par_iter(self) -> Self::Iter {
+		self.par_iter_mut()
+	}
+}
+
+impl<K: Ord + Send, V: Send> FromParallelIterator<(K, V)> for IdentityMap<K, V> {
+	/// Gathers the incoming pairs in parallel into a temporary buffer, then builds the map through the same serial bulk-construction path as [`FromIterator::from_iter`](FromIterator::from_iter).
+	#[inline]
+	fn from_par_iter<I: IntoParallelIterator<Item = (K, V)>>(par_iter: I) -> Self {
+		let pairs: StdVec<_> = par_iter.into_par_iter().collect();
+		Self::from_iter(pairs)
+	}
+}
+
+impl<K, V, A> ParallelExtend<(K, V)> for IdentityMap<K, V, A>
+where
+	K: Ord + Send,
+	V: Send,
+	A: Allocator,
+{
+	#[inline(always)]
+	fn par_extend<I: IntoParallelIterator<Item = (K, V)>>(&mut self, par_iter: I) {
+		self.par_extend(par_iter);
+	}
+}
@@ -0,0 +1,106 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use core::borrow::Borrow;
+use core::fmt::{self, Debug, Formatter};
+
+/// A borrowed, sorted view of an [identity map](crate::IdentityMap)'s key-value pairs.
+///
+/// This is constructed by the [`entries`](crate::IdentityMap::entries) method on [`IdentityMap`](crate::IdentityMap), or by [`split_at`](Self::split_at) on an existing slice.
+#[must_use]
+#[derive(Clone, Copy)]
+pub struct Slice<'a, K, V> {
+	pairs: &'a [(K, V)],
+}
+
+impl<'a, K, V> Slice<'a, K, V> {
+	#[inline(always)]
+	pub(crate) fn new(pairs: &'a [(K, V)]) -> Self {
+		Self { pairs }
+	}
+
+	/// Gets the number of key-value pairs in the slice.
+	#[inline(always)]
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.pairs.len()
+	}
+
+	/// Checks if the slice is empty.
+	#[inline(always)]
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.pairs.is_empty()
+	}
+
+	/// Borrows the first key-value pair.
+	#[inline(always)]
+	#[must_use]
+	pub fn first(&self) -> Option<(&'a K, &'a V)> {
+		self.pairs.first().map(|(k, v)| (k, v))
+	}
+
+	/// Borrows the last key-value pair.
+	#[inline(always)]
+	#[must_use]
+	pub fn last(&self) -> Option<(&'a K, &'a V)> {
+		self.pairs.last().map(|(k, v)| (k, v))
+	}
+
+	/// Borrows the key-value pair at the specified index.
+	#[inline(always)]
+	#[must_use]
+	pub fn get_index(&self, index: usize) -> Option<(&'a K, &'a V)> {
+		self.pairs.get(index).map(|(k, v)| (k, v))
+	}
+
+	/// Binary-searches the slice for a key.
+	///
+	/// # Errors
+	///
+	/// If the key is not found, then the index at which it could be inserted whilst maintaining order is returned instead.
+	#[inline]
+	pub fn binary_search<Q>(&self, key: &Q) -> Result<usize, usize>
+	where
+		K: Borrow<Q>,
+		Q: Ord + ?Sized,
+	{
+		self.pairs.binary_search_by(|(other_key, _)| {
+			let other_key = Borrow::<Q>::borrow(other_key);
+			other_key.cmp(key)
+		})
+	}
+
+	/// Returns the partition point of the slice according to the given predicate.
+	///
+	/// The slice is assumed to already be partitioned according to the predicate.
+	#[inline]
+	#[must_use]
+	pub fn partition_point<F: FnMut(&(K, V)) -> bool>(&self, pred: F) -> usize {
+		self.pairs.partition_point(pred)
+	}
+
+	/// Divides the slice into two at an index.
+	///
+	/// # Panics
+	///
+	/// Panics if `mid` is greater than the slice's length.
+	#[inline]
+	#[must_use]
+	#[track_caller]
+	pub fn split_at(&self, mid: usize) -> (Self, Self) {
+		let (lo, hi) = self.pairs.split_at(mid);
+
+		(Self::new(lo), Self::new(hi))
+	}
+}
+
+impl<K, V> Debug for Slice<'_, K, V>
+where
+	K: Debug,
+	V: Debug,
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.debug_tuple("Slice").field(&self.pairs).finish()
+	}
+}
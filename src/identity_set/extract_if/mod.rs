@@ -0,0 +1,153 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::identity_set::IdentitySet;
+
+use allocator_api2::alloc::Allocator;
+use core::fmt::{self, Debug, Formatter};
+use core::ptr;
+
+/// Identity set extraction iterator.
+///
+/// This iterator is constructed by the [`extract_if`](IdentitySet::extract_if) method on [`IdentitySet`].
+///
+/// Every key for which the held predicate returns `true` is moved out and yielded by this iterator; the remaining keys are shifted down to close the resulting gaps, keeping the set contiguous and sorted.
+/// This compaction also happens if the iterator is dropped before being fully consumed, or if the predicate panics.
+#[must_use]
+pub struct ExtractIf<'a, T, A, F>
+where
+	A: Allocator,
+	F: FnMut(&T) -> bool,
+{
+	set: &'a mut IdentitySet<T, A>,
+
+	idx:     usize,
+	del:     usize,
+	old_len: usize,
+
+	panic_flag: bool,
+
+	pred: F,
+}
+
+impl<'a, T, A, F> ExtractIf<'a, T, A, F>
+where
+	A: Allocator,
+	F: FnMut(&T) -> bool,
+{
+	#[inline]
+	pub(crate) fn new(set: &'a mut IdentitySet<T, A>, pred: F) -> Self {
+		let old_len = set.len();
+
+		// SAFETY: Zeroing the length is always sound, and it
+		// guarantees that the set cannot observe the
+		// in-progress (and possibly partially read) buffer
+		// if this iterator is leaked or `pred` panics.
+		unsafe { set.as_mut_map().as_mut_vec().set_len(0x0) };
+
+		Self { set, idx: 0x0, del: 0x0, old_len, panic_flag: false, pred }
+	}
+}
+
+impl<T, A, F> Debug for ExtractIf<'_, T, A, F>
+where
+	A: Allocator,
+	F: FnMut(&T) -> bool,
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.debug_struct("ExtractIf").finish_non_exhaustive()
+	}
+}
+
+impl<T, A, F> Drop for ExtractIf<'_, T, A, F>
+where
+	A: Allocator,
+	F: FnMut(&T) -> bool,
+{
+	#[inline]
+	fn drop(&mut self) {
+		let tail_len = self.old_len - self.idx;
+
+		if self.del > 0x0 && tail_len > 0x0 {
+			// SAFETY: Both the source and destination ranges
+			// lie within the original, still-allocated buf-
+			// fer, and `dst` always lags behind `src`.
+			unsafe {
+				let ptr = self.set.as_mut_ptr();
+
+				let src = ptr.add(self.idx);
+				let dst = ptr.add(self.idx - self.del);
+
+				ptr::copy(src, dst, tail_len);
+			}
+		}
+
+		// SAFETY: Every index below `old_len - del` now holds
+		// a live, uniquely-owned key.
+		unsafe { self.set.as_mut_map().as_mut_vec().set_len(self.old_len - self.del) };
+
+		// Remove every matching key that was not yet visited,
+		// unless `pred` has already panicked.
+		if !self.panic_flag {
+			let mut visited = self.idx - self.del;
+			let pred = &mut self.pred;
+
+			self.set.retain(|key| {
+				if visited > 0x0 { visited -= 0x1; return true };
+				!pred(key)
+			});
+		}
+	}
+}
+
+impl<T, A, F> Iterator for ExtractIf<'_, T, A, F>
+where
+	A: Allocator,
+	F: FnMut(&T) -> bool,
+{
+	type Item = T;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		while self.idx < self.old_len {
+			let i = self.idx;
+
+			// SAFETY: `i` is in bounds of the original buffer,
+			// which stays allocated (and, besides compaction
+			// of already-visited slots, untouched) for as
+			// long as this iterator lives.
+			let key = unsafe { &mut *self.set.as_mut_ptr().add(i) };
+
+			self.panic_flag = true;
+			let matches = (self.pred)(key);
+			self.panic_flag = false;
+			self.idx += 1;
+
+			if matches {
+				self.del += 1;
+
+				// SAFETY: This key is moved out here and will
+				// not be read again: it is either overwritten
+				// by a later retained key or falls past the
+				// set's corrected length.
+				return Some(unsafe { ptr::read(key) });
+			} else if self.del > 0x0 {
+				// SAFETY: `dst` always lags behind `src`, and
+				// both lie within the original buffer.
+				unsafe {
+					let src: *const T = key;
+					let dst = self.set.as_mut_ptr().add(i - self.del);
+
+					ptr::copy_nonoverlapping(src, dst, 0x1);
+				}
+			}
+		}
+
+		None
+	}
+
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0x0, Some(self.old_len - self.idx))
+	}
+}
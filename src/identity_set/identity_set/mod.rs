@@ -29,21 +29,31 @@
 #[cfg(test)]
 mod test;
 
+#[cfg(feature = "serde")]
+mod serde;
+
+use crate::{TryInsertError, TryReserveError};
 use crate::identity_map::IdentityMap;
 use crate::identity_set::{
 	Difference,
+	ExtractIf,
 	Intersection,
+	IntersectionAll,
 	IntoIter,
 	Iter,
+	Range,
+	Slice,
 	SymmetricDifference,
 	Union,
+	UnionAll,
 };
 
 use allocator_api2::alloc::{Allocator, Global};
 use core::borrow::Borrow;
+use core::cmp::Ordering;
 use core::fmt::{self, Debug, Formatter};
 use core::hash::{Hash, Hasher};
-use core::ops::{BitAnd, BitOr, BitXor, Sub};
+use core::ops::{BitAnd, BitOr, BitXor, RangeBounds, Sub};
 
 /// An ordered identity set.
 ///
@@ -79,6 +89,18 @@ impl<T> IdentitySet<T> {
 		Self::with_capacity_in(cap, Global)
 	}
 
+	/// Preallocates a new identity set, without panicking.
+	///
+	/// This is the fallible counterpart to [`with_capacity`](Self::with_capacity).
+	///
+	/// # Errors
+	///
+	/// If `[T; cap]` could not be allocated using the global allocator, then this function returns an appropriate [`TryReserveError`] instead of panicking.
+	#[inline(always)]
+	pub fn try_with_capacity(cap: usize) -> Result<Self, TryReserveError> {
+		Self::try_with_capacity_in(cap, Global)
+	}
+
 	/// Constructs a new identity set from raw parts.
 	///
 	/// # Safety
@@ -117,6 +139,21 @@ impl<T, A: Allocator> IdentitySet<T, A> {
 		Self { map }
 	}
 
+	/// Preallocates a new identity set with a specific allocator, without panicking.
+	///
+	/// This is the fallible counterpart to [`with_capacity_in`](Self::with_capacity_in).
+	/// It is intended for allocator-constrained contexts that must recover from failed growth instead of aborting the process.
+	///
+	/// # Errors
+	///
+	/// If `[T; cap]` could not be allocated with the given allocator, then this method returns an appropriate [`TryReserveError`] instead of panicking.
+	#[inline(always)]
+	pub fn try_with_capacity_in(cap: usize, alloc: A) -> Result<Self, TryReserveError> {
+		let map = IdentityMap::try_with_capacity_in(cap, alloc)?;
+
+		Ok(Self { map })
+	}
+
 	/// Constructs a new identity set from raw parts.
 	///
 	/// # Safety
@@ -154,6 +191,17 @@ impl<T, A: Allocator> IdentitySet<T, A> {
 		self.map.retain(|k, _| f(k));
 	}
 
+	/// Extracts all keys matching a predicate, yielding them through an iterator.
+	///
+	/// Every key `k` for which `pred(k)` returns `true` is moved out of the set and yielded.
+	/// The remaining keys stay in place and in order.
+	///
+	/// If the returned iterator is dropped before being fully consumed, it will still remove and drop all matching keys from the remainder of the set.
+	#[inline(always)]
+	pub fn extract_if<F: FnMut(&T) -> bool>(&mut self, pred: F) -> ExtractIf<'_, T, A, F> {
+		ExtractIf::new(self, pred)
+	}
+
 	/// Clears the set.
 	///
 	/// All contained keys are dropped after a call to this method.
@@ -175,6 +223,45 @@ impl<T, A: Allocator> IdentitySet<T, A> {
 		self.map.reserve(count);
 	}
 
+	/// Reserves additional capacity for the set, without panicking.
+	///
+	/// This is the fallible counterpart to [`reserve`](Self::reserve).
+	///
+	/// # Errors
+	///
+	/// If the requested capacity would overflow [`isize::MAX`] bytes, or the allocator could not fulfil the allocation, then this method returns an appropriate [`TryReserveError`] instead of panicking.
+	#[inline(always)]
+	pub fn try_reserve(&mut self, count: usize) -> Result<(), TryReserveError> {
+		self.map.try_reserve(count)
+	}
+
+	/// Reserves the exact additional capacity for the set, without over-allocating.
+	///
+	/// Unlike [`reserve`](Self::reserve), which may grow the buffer geometrically to amortise the cost of repeated insertions, this grows the buffer to hold precisely `count` more elements than its current length.
+	/// Prefer [`reserve`](Self::reserve) when inserting incrementally; this method suits a single, known-size reservation ahead of a bulk fill.
+	///
+	/// # Panics
+	///
+	/// This method will panic if the internal buffer could not be grown.
+	/// It will also panic if the new capacity of the set is greater than [`isize::MAX`].
+	#[inline(always)]
+	#[track_caller]
+	pub fn reserve_exact(&mut self, count: usize) {
+		self.map.reserve_exact(count);
+	}
+
+	/// Reserves the exact additional capacity for the set, without panicking.
+	///
+	/// This is the fallible counterpart to [`reserve_exact`](Self::reserve_exact).
+	///
+	/// # Errors
+	///
+	/// If the requested capacity would overflow [`isize::MAX`] bytes, or the allocator could not fulfil the allocation, then this method returns an appropriate [`TryReserveError`] instead of panicking.
+	#[inline(always)]
+	pub fn try_reserve_exact(&mut self, count: usize) -> Result<(), TryReserveError> {
+		self.map.try_reserve_exact(count)
+	}
+
 	/// Shrinks the set to a specified length.
 	///
 	/// The capacity is shrunk such that it exactly contains the current data.
@@ -268,6 +355,13 @@ impl<T, A: Allocator> IdentitySet<T, A> {
 		unsafe { &mut *(&raw mut *self.map.as_mut_slice() as *mut [T]) }
 	}
 
+	/// Gets a sorted [`Slice`] view over the set's keys.
+	#[inline(always)]
+	#[must_use]
+	pub fn entries(&self) -> Slice<'_, T> {
+		Slice::new(self.map.entries())
+	}
+
 	/// Borrows the set as a map.
 	#[allow(unused)]
 	#[inline(always)]
@@ -312,6 +406,77 @@ where
 		self.map.insert(key, ()).is_some()
 	}
 
+	/// Inserts all keys from another set, overwriting duplicates.
+	///
+	/// The other set `other` will be completely cleared.
+	///
+	/// As both sets are already sorted, this runs in *O*(*n* + *m*) via [`IdentityMap::append`].
+	#[inline(always)]
+	#[track_caller]
+	pub fn append(&mut self, other: &mut Self)
+	where
+		A: Clone,
+	{
+		self.map.append(&mut other.map);
+	}
+
+	/// Splits the set into two at the given key.
+	///
+	/// Returns a newly-allocated set containing every key greater than or equal to `key`.
+	/// `self` retains every key strictly less than it.
+	#[inline(always)]
+	#[track_caller]
+	pub fn split_off(&mut self, key: &T) -> Self
+	where
+		A: Clone,
+	{
+		let map = self.map.split_off(key);
+		Self { map }
+	}
+
+	/// Constructs a new identity set from an iterator of keys, without sorting them.
+	///
+	/// See [`IdentityMap::from_sorted`].
+	#[inline]
+	pub fn from_sorted<I: IntoIterator<Item = T>>(iter: I) -> Self
+	where
+		A: Default,
+	{
+		let map = IdentityMap::from_sorted(iter.into_iter().map(|key| (key, ())));
+
+		Self { map }
+	}
+
+	/// Extends the set from an iterator of keys, without sorting them relative to each other or to the set's existing contents.
+	///
+	/// See [`IdentityMap::extend_sorted`].
+	#[inline(always)]
+	pub fn extend_sorted<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+		self.map.extend_sorted(iter.into_iter().map(|key| (key, ())));
+	}
+
+	/// Inserts a new key into the set, without checking that it is new or properly ordered.
+	///
+	/// This is a fast path, analogous to hashbrown's `insert_unique_unchecked`: it skips both the binary search and the shift that [`insert`](Self::insert) would otherwise perform, and simply appends.
+	///
+	/// See [`IdentityMap::insert_sorted`].
+	#[inline(always)]
+	pub fn insert_sorted(&mut self, key: T) {
+		self.map.insert_sorted(key, ());
+	}
+
+	/// Inserts a new key into the set, without panicking.
+	///
+	/// This is the fallible counterpart to [`insert`](Self::insert).
+	///
+	/// # Errors
+	///
+	/// If the set did not already hold `key` and could not grow its buffer to accommodate it, then this method returns a [`TryInsertError`] -- carrying back the un-inserted key -- instead of panicking.
+	#[inline(always)]
+	pub fn try_insert(&mut self, key: T) -> Result<bool, TryInsertError<T, ()>> {
+		self.map.try_insert(key, ()).map(|value| value.is_some())
+	}
+
 	/// Takes a specific key out from the set.
 	///
 	/// If the provided key was not present in the set, then this method will instead return a [`None`] instance.
@@ -380,6 +545,104 @@ where
 	pub fn union<'a>(&'a self, other: &'a Self) -> Union<'a, T, A> {
 		Union::new(self, other)
 	}
+
+	/// Gets an iterator denoting the [union](https://en.wikipedia.org/wiki/Union_(set_theory)) across any number of sets.
+	///
+	/// The sets are merged in a single streaming pass via a min-heap of their current heads, rather than by folding pairwise [`union`](Self::union) calls. This is far more efficient and scales to dozens of sets in *O*(*n* log *k*), where *n* is the total number of elements and *k* is the number of sets.
+	#[inline(always)]
+	pub fn union_all<'a, I>(sets: I) -> UnionAll<'a, T>
+	where
+		T: 'a,
+		A: 'a,
+		I: IntoIterator<Item = &'a Self>,
+	{
+		UnionAll::new(sets)
+	}
+
+	/// Gets an iterator denoting the [intersection](https://en.wikipedia.org/wiki/Intersection) across any number of sets.
+	///
+	/// The sets are merged in a single streaming pass via a min-heap of their current heads, rather than by folding pairwise [`intersection`](Self::intersection) calls. A value is only yielded once every source set currently presents it as its smallest remaining head, and the iterator stops as soon as any source set is exhausted. This is far more efficient and scales to dozens of sets in *O*(*n* log *k*), where *n* is the total number of elements and *k* is the number of sets.
+	#[inline(always)]
+	pub fn intersection_all<'a, I>(sets: I) -> IntersectionAll<'a, T>
+	where
+		T: 'a,
+		A: 'a,
+		I: IntoIterator<Item = &'a Self>,
+	{
+		IntersectionAll::new(sets)
+	}
+
+	/// Checks if `self` is a subset of another set.
+	///
+	/// This walks both sets' sorted buffers with a single linear two-pointer merge, rather than materialising an [`intersection`](Self::intersection) and counting, giving *O*(*n* + *m*) with no allocation.
+	#[must_use]
+	pub fn is_subset(&self, other: &Self) -> bool {
+		if self.len() > other.len() { return false };
+
+		let (mut i, mut j) = (0x0, 0x0);
+
+		let (lhs, rhs) = (self.as_slice(), other.as_slice());
+
+		while i < lhs.len() {
+			if j >= rhs.len() { return false };
+
+			match lhs[i].cmp(&rhs[j]) {
+				Ordering::Less => return false,
+
+				Ordering::Equal => {
+					i += 0x1;
+					j += 0x1;
+				}
+
+				Ordering::Greater => j += 0x1,
+			}
+		}
+
+		true
+	}
+
+	/// Checks if `self` is a superset of another set.
+	#[inline(always)]
+	#[must_use]
+	pub fn is_superset(&self, other: &Self) -> bool {
+		other.is_subset(self)
+	}
+
+	/// Checks if `self` and another set share no keys.
+	///
+	/// This walks both sets' sorted buffers with a single linear two-pointer merge, rather than materialising an [`intersection`](Self::intersection) and checking for emptiness, giving *O*(*n* + *m*) with no allocation.
+	#[must_use]
+	pub fn is_disjoint(&self, other: &Self) -> bool {
+		let (mut i, mut j) = (0x0, 0x0);
+
+		let (lhs, rhs) = (self.as_slice(), other.as_slice());
+
+		while i < lhs.len() && j < rhs.len() {
+			match lhs[i].cmp(&rhs[j]) {
+				Ordering::Less    => i += 0x1,
+				Ordering::Greater => j += 0x1,
+				Ordering::Equal   => return false,
+			}
+		}
+
+		true
+	}
+
+	/// Gets an iterator of the keys whose value falls within the specified range.
+	///
+	/// # Panics
+	///
+	/// Panics if `range`'s start bound lies strictly after its end bound, or if both bounds are equal and excluded.
+	#[inline(always)]
+	#[track_caller]
+	pub fn range<U, R>(&self, range: R) -> Range<'_, T>
+	where
+		T: Borrow<U>,
+		U: Ord + ?Sized,
+		R: RangeBounds<U>,
+	{
+		Range::new(self.map.range(range))
+	}
 }
 
 impl<T, A> BitAnd for &IdentitySet<T, A>
@@ -454,6 +717,14 @@ where
 	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
 		let iter = iter.into_iter();
 
+		// See `IdentityMap::extend` for why an empty set can
+		// take the faster sort-and-dedup bulk path.
+
+		if self.is_empty() {
+			self.map.extend(iter.map(|key| (key, ())));
+			return;
+		}
+
 		self.reserve(iter.size_hint().0);
 
 		for key in iter {
@@ -480,15 +751,8 @@ where
 {
 	#[inline]
 	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-		let iter = iter.into_iter();
-
-		let mut this = Self::with_capacity_in(iter.size_hint().0, Default::default());
-
-		for key in iter {
-			this.insert(key);
-		}
-
-		this
+		let map = iter.into_iter().map(|key| (key, ())).collect();
+		Self { map }
 	}
 }
 
@@ -536,18 +800,6 @@ where
 	}
 }
 
-#[cfg(feature = "serde")]
-impl<T, A> serde::Serialize for IdentitySet<T, A>
-where
-	T: serde::Serialize,
-	A: Allocator,
-{
-	#[inline(always)]
-	fn serialize<S: serde::Serializer>(&self, serialiser: S) -> Result<S::Ok, S::Error> {
-		serialiser.collect_seq(self.iter())
-	}
-}
-
 impl<T, A> Sub for &IdentitySet<T, A>
 where
 	T: Clone + Ord,
@@ -2,6 +2,7 @@
 
 use crate::identity_set::IdentitySet;
 
+use alloc::vec::Vec as StdVec;
 use allocator_api2::alloc::Allocator;
 use core::any::type_name;
 use core::fmt::{self, Formatter};
@@ -40,16 +41,19 @@ where
 
 	#[inline]
 	fn visit_seq<A: SeqAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
-		let alloc = Default::default();
-		let cap   = map.size_hint().unwrap_or_default();
+		let cap = map.size_hint().unwrap_or_default();
 
-		let mut this = IdentitySet::with_capacity_in(cap, alloc);
+		let mut buf = StdVec::with_capacity(cap);
 
 		while let Some(key) = map.next_element()? {
-			this.insert(key);
+			buf.push(key);
 		}
 
-		Ok(this)
+		// Building from the collected keys in one pass sorts
+		// and dedups once, rather than doing a binary search
+		// and shift for every key.
+
+		Ok(IdentitySet::from_iter(buf))
 	}
 }
 
@@ -72,6 +72,51 @@ fn test_identity_set() {
 	assert!(!set.contains(&'\0'));
 }
 
+#[test]
+fn test_identity_set_try_with_capacity() {
+	let set = IdentitySet::<u8>::try_with_capacity(0x10).unwrap();
+
+	assert!(set.capacity() >= 0x10);
+	assert!(set.len() == 0x0);
+	assert!(set.is_empty());
+
+	assert!(IdentitySet::<u8>::try_with_capacity(usize::MAX).is_err());
+}
+
+#[test]
+fn test_identity_set_reserve_exact() {
+	let mut set = IdentitySet::<u8>::new();
+
+	set.reserve_exact(0x10);
+
+	assert!(set.capacity() >= 0x10);
+
+	assert!(set.try_reserve_exact(usize::MAX).is_err());
+}
+
+#[test]
+fn test_identity_set_shrink() {
+	let mut set = IdentitySet::<u8>::with_capacity(0x100);
+
+	set.insert(0x00);
+	set.insert(0x10);
+
+	assert!(set.capacity() >= 0x100);
+
+	set.shrink_to(0x20);
+
+	assert!(set.capacity() >= 0x20);
+	assert_eq!(set.len(), 0x2);
+	assert!(set.contains(&0x00));
+	assert!(set.contains(&0x10));
+
+	set.shrink_to_fit();
+
+	assert_eq!(set.capacity(), set.len());
+	assert!(set.contains(&0x00));
+	assert!(set.contains(&0x10));
+}
+
 #[test]
 fn test_identity_set_from_iter() {
 	let data = [
@@ -199,6 +244,203 @@ fn test_identity_set_ops() {
 	assert_eq!(iter.next(), None);
 }
 
+#[test]
+fn test_identity_set_entries() {
+	let set = IdentitySet::<u8>::from([0x00, 0x10, 0x20, 0x30]);
+
+	let entries = set.entries();
+
+	assert_eq!(entries.len(), 0x4);
+	assert!(!entries.is_empty());
+	assert_eq!(entries.first(), Some(&0x00));
+	assert_eq!(entries.last(), Some(&0x30));
+	assert_eq!(entries.get_index(0x2), Some(&0x20));
+	assert_eq!(entries.get_index(0xFF), None);
+	assert_eq!(entries.binary_search(&0x20), Ok(0x2));
+	assert_eq!(entries.binary_search(&0x25), Err(0x3));
+	assert_eq!(entries.partition_point(|key| *key < 0x20), 0x2);
+
+	let (lo, hi) = entries.split_at(0x2);
+
+	assert_eq!(lo.len(), 0x2);
+	assert_eq!(lo.last(), Some(&0x10));
+	assert_eq!(hi.len(), 0x2);
+	assert_eq!(hi.first(), Some(&0x20));
+
+	let empty = IdentitySet::<u8>::new();
+
+	assert!(empty.entries().is_empty());
+	assert_eq!(empty.entries().first(), None);
+}
+
+#[test]
+fn test_identity_set_relations() {
+	let set0: IdentitySet<i32> = [0x00, 0x01, 0x02, 0x03, 0x04].into();
+	let set1: IdentitySet<i32> = [0x01, 0x03].into();
+	let set2: IdentitySet<i32> = [0x05, 0x06].into();
+	let set3: IdentitySet<i32> = [0x03, 0x04, 0x05].into();
+
+	assert!(set1.is_subset(&set0));
+	assert!(!set0.is_subset(&set1));
+	assert!(set0.is_subset(&set0));
+
+	assert!(set0.is_superset(&set1));
+	assert!(!set1.is_superset(&set0));
+	assert!(set0.is_superset(&set0));
+
+	assert!(set0.is_disjoint(&set2));
+	assert!(set2.is_disjoint(&set0));
+	assert!(!set0.is_disjoint(&set1));
+	assert!(!set0.is_disjoint(&set3));
+	assert!(!set3.is_disjoint(&set0));
+
+	let empty = IdentitySet::<i32>::new();
+
+	assert!(empty.is_subset(&set0));
+	assert!(set0.is_superset(&empty));
+	assert!(empty.is_disjoint(&set0));
+}
+
+#[test]
+fn test_identity_set_union_all() {
+	let set0: IdentitySet<i32> = [0x00, 0x01, 0x02].into();
+	let set1: IdentitySet<i32> = [0x01, 0x03].into();
+	let set2: IdentitySet<i32> = [0x02, 0x03, 0x04].into();
+
+	let union: Vec<_> = IdentitySet::union_all([&set0, &set1, &set2]).copied().collect();
+
+	assert_eq!(union, [0x00, 0x01, 0x02, 0x03, 0x04]);
+
+	// An empty list of sets yields an empty union.
+
+	let sets: [&IdentitySet<i32>; 0] = [];
+
+	assert_eq!(IdentitySet::union_all(sets).count(), 0x0);
+
+	// A single set's union with itself is itself.
+
+	let union: Vec<_> = IdentitySet::union_all([&set0]).copied().collect();
+
+	assert_eq!(union, [0x00, 0x01, 0x02]);
+
+	// Sets containing identical elements are deduplicated even
+	// when several of them share the exact same head.
+
+	let set3: IdentitySet<i32> = [0x00].into();
+	let set4: IdentitySet<i32> = [0x00].into();
+	let set5: IdentitySet<i32> = [0x00].into();
+
+	let union: Vec<_> = IdentitySet::union_all([&set3, &set4, &set5]).copied().collect();
+
+	assert_eq!(union, [0x00]);
+
+	let empty = IdentitySet::<i32>::new();
+
+	let union: Vec<_> = IdentitySet::union_all([&empty, &set0]).copied().collect();
+
+	assert_eq!(union, [0x00, 0x01, 0x02]);
+}
+
+#[test]
+fn test_identity_set_intersection_all() {
+	let set0: IdentitySet<i32> = [0x00, 0x01, 0x02, 0x03].into();
+	let set1: IdentitySet<i32> = [0x01, 0x02, 0x03, 0x04].into();
+	let set2: IdentitySet<i32> = [0x01, 0x02, 0x05].into();
+
+	let intersection: Vec<_> = IdentitySet::intersection_all([&set0, &set1, &set2]).copied().collect();
+
+	assert_eq!(intersection, [0x01, 0x02]);
+
+	// An empty list of sets yields an empty intersection.
+
+	let sets: [&IdentitySet<i32>; 0] = [];
+
+	assert_eq!(IdentitySet::intersection_all(sets).count(), 0x0);
+
+	// A single set's intersection with itself is itself.
+
+	let intersection: Vec<_> = IdentitySet::intersection_all([&set0]).copied().collect();
+
+	assert_eq!(intersection, [0x00, 0x01, 0x02, 0x03]);
+
+	// As soon as one source set is exhausted -- including an
+	// empty one -- no further value can be common to all sets.
+
+	let empty = IdentitySet::<i32>::new();
+
+	assert_eq!(IdentitySet::intersection_all([&set0, &empty]).count(), 0x0);
+
+	// Disjoint sets intersect to nothing.
+
+	let set3: IdentitySet<i32> = [0x10, 0x11].into();
+
+	assert_eq!(IdentitySet::intersection_all([&set0, &set3]).count(), 0x0);
+}
+
+#[test]
+fn test_identity_set_sorted() {
+	let mut set = IdentitySet::<u8>::from_sorted([0x00, 0x10, 0x20]);
+
+	assert_eq!(set.len(), 0x3);
+	assert!(set.contains(&0x10));
+
+	set.extend_sorted([0x30, 0x40]);
+
+	assert_eq!(set.len(), 0x5);
+	assert!(set.contains(&0x30));
+	assert!(set.contains(&0x40));
+
+	set.insert_sorted(0x50);
+
+	assert_eq!(set.len(), 0x6);
+	assert!(set.contains(&0x50));
+}
+
+#[test]
+fn test_identity_set_extract_if() {
+	let mut set = IdentitySet::<u8>::from([0x00, 0x10, 0x20, 0x30, 0x40]);
+
+	let extracted: Vec<_> = set.extract_if(|key| *key % 0x20 == 0x0).collect();
+
+	assert_eq!(extracted, [0x00, 0x20, 0x40]);
+
+	assert_eq!(set.len(), 0x2);
+	assert!(set.contains(&0x10));
+	assert!(set.contains(&0x30));
+
+	// Dropping the iterator early still leaves the set fully
+	// compacted.
+
+	let mut set = IdentitySet::<u8>::from([0x00, 0x10, 0x20]);
+
+	drop(set.extract_if(|key| *key == 0x10));
+
+	assert_eq!(set.len(), 0x2);
+	assert!(set.contains(&0x00));
+	assert!(set.contains(&0x20));
+
+	// A predicate that never matches extracts nothing and
+	// leaves the set untouched.
+
+	assert_eq!(set.extract_if(|_| false).count(), 0x0);
+	assert_eq!(set.len(), 0x2);
+}
+
+#[test]
+fn test_identity_set_retain() {
+	let mut set = IdentitySet::<u8>::from([0x00, 0x10, 0x20, 0x30, 0x40]);
+
+	set.retain(|key| *key % 0x20 != 0x0);
+
+	assert_eq!(set.len(), 0x2);
+	assert!(set.contains(&0x10));
+	assert!(set.contains(&0x30));
+
+	set.retain(|_| false);
+
+	assert!(set.is_empty());
+}
+
 #[test]
 fn test_identity_set_serialise_deserialise() {
 	let input = IdentitySet::<isize>::from([
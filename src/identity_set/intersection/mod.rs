@@ -3,7 +3,9 @@
 use crate::identity_set::{IdentitySet, Iter};
 
 use allocator_api2::alloc::Allocator;
-use core::iter::FusedIterator;
+use core::cmp::Ordering;
+use core::iter::{FusedIterator, Peekable};
+use core::marker::PhantomData;
 
 /// Iterator denoting the [intersection](https://en.wikipedia.org/wiki/Intersection) between two [identity sets](IdentitySet).
 #[must_use]
@@ -13,8 +15,10 @@ where
 	T: Ord,
 	A: Allocator,
 {
-	this:  Iter<'a, T>,
-	other: &'a IdentitySet<T, A>,
+	this:  Peekable<Iter<'a, T>>,
+	other: Peekable<Iter<'a, T>>,
+
+	_alloc: PhantomData<A>,
 }
 
 impl<'a, T, A: Allocator> Intersection<'a, T, A>
@@ -25,8 +29,10 @@ where
 	/// Constructs a new iterator denoting the [intersection](https://en.wikipedia.org/wiki/Intersection) between two [identity sets](IdentitySet).
 	#[inline(always)]
 	pub(crate) fn new(this: &'a IdentitySet<T, A>, other: &'a IdentitySet<T, A>) -> Self {
-		let this = this.iter();
-		Self { this, other }
+		let this  = this.iter().peekable();
+		let other = other.iter().peekable();
+
+		Self { this, other, _alloc: PhantomData }
 	}
 }
 
@@ -43,13 +49,26 @@ where
 {
 	type Item = &'a T;
 
-	#[inline(always)]
+	#[inline]
 	fn next(&mut self) -> Option<Self::Item> {
-		for key in self.this.by_ref() {
-			if self.other.contains(key) { return Some(key) };
-		}
+		// Merge-join the two sorted streams: advance what-
+		// ever side holds the smaller element, and only
+		// emit when both sides currently agree.
 
-		None
+		loop {
+			let lhs = self.this.peek()?;
+			let rhs = self.other.peek()?;
+
+			match lhs.cmp(rhs) {
+				Ordering::Less    => { self.this.next(); }
+				Ordering::Greater => { self.other.next(); }
+
+				Ordering::Equal => {
+					self.other.next();
+					return self.this.next();
+				}
+			}
+		}
 	}
 
 	#[inline(always)]
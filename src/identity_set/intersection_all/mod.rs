@@ -0,0 +1,133 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::identity_set::IdentitySet;
+
+use alloc::collections::BinaryHeap;
+use allocator_api2::alloc::Allocator;
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Formatter};
+use core::iter::FusedIterator;
+use core::slice::Iter as SliceIter;
+
+/// A single set's current head and remaining elements, ordered for a min-heap on `head`.
+struct HeapEntry<'a, T> {
+	head: &'a T,
+	rest: SliceIter<'a, T>,
+}
+
+impl<T: Ord> PartialEq for HeapEntry<'_, T> {
+	#[inline(always)]
+	fn eq(&self, other: &Self) -> bool {
+		self.head == other.head
+	}
+}
+
+impl<T: Ord> Eq for HeapEntry<'_, T> { }
+
+impl<T: Ord> PartialOrd for HeapEntry<'_, T> {
+	#[inline(always)]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl<T: Ord> Ord for HeapEntry<'_, T> {
+	#[inline(always)]
+	fn cmp(&self, other: &Self) -> Ordering {
+		// Reversed so that `BinaryHeap` -- which is
+		// otherwise a max-heap -- pops the smallest
+		// head first.
+
+		other.head.cmp(self.head)
+	}
+}
+
+/// Iterator denoting the [intersection](https://en.wikipedia.org/wiki/Intersection) across any number of [identity sets](IdentitySet).
+///
+/// This is constructed by the [`intersection_all`](IdentitySet::intersection_all) function.
+///
+/// Every source set is already sorted, so the sets are merged in a single streaming pass using a min-heap of their current heads, rather than by folding pairwise [intersections](IdentitySet::intersection). A value is only yielded once every source set currently presents it as their smallest remaining head; as soon as any source set is exhausted, no further value can be common to all of them, and the iterator stops.
+#[must_use]
+pub struct IntersectionAll<'a, T> {
+	heap:  BinaryHeap<HeapEntry<'a, T>>,
+	count: usize,
+}
+
+impl<'a, T: Ord> IntersectionAll<'a, T> {
+	/// Constructs a new iterator denoting the intersection across any number of identity sets.
+	pub(crate) fn new<A, I>(sets: I) -> Self
+	where
+		A: Allocator + 'a,
+		I: IntoIterator<Item = &'a IdentitySet<T, A>>,
+	{
+		let mut heap  = BinaryHeap::new();
+		let mut count = 0x0;
+
+		for set in sets {
+			count += 0x1;
+
+			let mut rest = set.as_slice().iter();
+
+			// An empty source set makes the whole intersection
+			// empty. Leaving this set's head out of the heap
+			// permanently undershoots `count`, which the itera-
+			// tor detects on its very first call.
+
+			if let Some(head) = rest.next() {
+				heap.push(HeapEntry { head, rest });
+			}
+		}
+
+		Self { heap, count }
+	}
+}
+
+impl<T: Debug> Debug for IntersectionAll<'_, T> {
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.debug_struct("IntersectionAll").finish_non_exhaustive()
+	}
+}
+
+impl<T: Ord> FusedIterator for IntersectionAll<'_, T> { }
+
+impl<'a, T: Ord> Iterator for IntersectionAll<'a, T> {
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			// Once fewer heads remain in the heap than there
+			// are source sets, at least one set has been ex-
+			// hausted, and no value can ever again be common
+			// to all of them.
+
+			if self.count == 0x0 || self.heap.len() < self.count {
+				return None;
+			}
+
+			let HeapEntry { head, mut rest } = self.heap.pop().unwrap();
+
+			let mut matched = 0x1;
+
+			if let Some(next) = rest.next() {
+				self.heap.push(HeapEntry { head: next, rest });
+			}
+
+			while let Some(top) = self.heap.peek() {
+				if top.head != head { break };
+
+				let HeapEntry { mut rest, .. } = self.heap.pop().unwrap();
+
+				matched += 0x1;
+
+				if let Some(next) = rest.next() {
+					self.heap.push(HeapEntry { head: next, rest });
+				}
+			}
+
+			if matched == self.count {
+				return Some(head);
+			}
+		}
+	}
+}
@@ -4,21 +4,37 @@
 
 mod difference;
 mod drain;
+mod extract_if;
 mod identity_set;
 mod intersection;
+mod intersection_all;
 mod into_iter;
 mod iter;
+mod range;
+mod slice;
 mod symmetric_difference;
 mod union;
+mod union_all;
+
+#[cfg(feature = "rayon")]
+mod rayon;
 
 pub use difference::Difference;
 pub use drain::Drain;
+pub use extract_if::ExtractIf;
 pub use identity_set::IdentitySet;
 pub use intersection::Intersection;
+pub use intersection_all::IntersectionAll;
 pub use into_iter::IntoIter;
 pub use iter::Iter;
+pub use range::Range;
+pub use slice::Slice;
 pub use symmetric_difference::SymmetricDifference;
 pub use union::Union;
+pub use union_all::UnionAll;
+
+#[cfg(feature = "rayon")]
+pub use rayon::ParIter;
 
 use core::cmp::Ordering;
 use core::iter::Peekable;
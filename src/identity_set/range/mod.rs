@@ -0,0 +1,72 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::identity_map;
+
+use core::fmt::{self, Debug, Formatter};
+use core::iter::FusedIterator;
+use core::ptr;
+
+/// Borrowing identity set range iterator.
+#[must_use]
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct Range<'a, T> {
+	iter: identity_map::Range<'a, T, ()>,
+}
+
+impl<'a, T> Range<'a, T> {
+	/// Constructs a new, borrowing identity set range iterator.
+	#[inline(always)]
+	pub(crate) fn new(iter: identity_map::Range<'a, T, ()>) -> Self {
+		Self { iter }
+	}
+
+	/// Gets a slice of the keys.
+	#[inline(always)]
+	pub(crate) fn as_slice(&self) -> &[T] {
+		let ptr = ptr::from_ref(self.iter.as_slice()) as *const [T];
+
+		// SAFETY: `(T, ())` is transparent to `T`.
+		unsafe { &*ptr }
+	}
+}
+
+impl<T: Debug> Debug for Range<'_, T> {
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.debug_tuple("Range").field(&self.as_slice()).finish()
+	}
+}
+
+impl<T> Default for Range<'_, T> {
+	#[inline(always)]
+	fn default() -> Self {
+		let iter = Default::default();
+		Self { iter }
+	}
+}
+
+impl<T> DoubleEndedIterator for Range<'_, T> {
+	#[inline(always)]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.iter.next_back().map(|(k, _)| k)
+	}
+}
+
+impl<T> ExactSizeIterator for Range<'_, T> { }
+
+impl<T> FusedIterator for Range<'_, T> { }
+
+impl<'a, T> Iterator for Range<'a, T> {
+	type Item = &'a T;
+
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.iter.next().map(|(k, _)| k)
+	}
+
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.iter.size_hint()
+	}
+}
@@ -0,0 +1,307 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+//! Optional [`rayon`] parallel iteration support.
+//!
+//! This module is only available when the `rayon` feature is enabled.
+
+use crate::identity_set::IdentitySet;
+
+use alloc::vec::Vec as StdVec;
+use allocator_api2::alloc::Allocator;
+use core::cmp::Ordering;
+use core::iter::Map as IterMap;
+use core::slice::Iter as SliceIter;
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{
+	FromParallelIterator,
+	IndexedParallelIterator,
+	IntoParallelIterator,
+	ParallelExtend,
+	ParallelIterator,
+};
+use rayon::vec::IntoIter as VecIntoIter;
+
+#[inline(always)]
+fn key_ref<T>(key: &T) -> &T {
+	key
+}
+
+/// A parallel iterator of the keys contained in an [`IdentitySet`].
+///
+/// This is constructed by the [`par_iter`](IdentitySet::par_iter) method on [`IdentitySet`].
+///
+/// As the set's backing buffer is already a single contiguous, sorted slice, this iterator's [`Producer`] just `split_at`s that slice -- no custom raw-table splitting (as e.g. `hashbrown` requires) is needed.
+#[must_use]
+pub struct ParIter<'a, T> {
+	slice: &'a [T],
+}
+
+impl<'a, T> ParIter<'a, T> {
+	#[inline(always)]
+	pub(crate) fn new(slice: &'a [T]) -> Self {
+		Self { slice }
+	}
+}
+
+struct IterProducer<'a, T> {
+	slice: &'a [T],
+}
+
+impl<'a, T: Sync> Producer for IterProducer<'a, T> {
+	type IntoIter = IterMap<SliceIter<'a, T>, fn(&'a T) -> &'a T>;
+	type Item = &'a T;
+
+	#[inline(always)]
+	fn into_iter(self) -> Self::IntoIter {
+		self.slice.iter().map(key_ref)
+	}
+
+	#[inline(always)]
+	fn split_at(self, index: usize) -> (Self, Self) {
+		let (lhs, rhs) = self.slice.split_at(index);
+
+		(IterProducer { slice: lhs }, IterProducer { slice: rhs })
+	}
+}
+
+impl<'a, T: Sync> ParallelIterator for ParIter<'a, T> {
+	type Item = &'a T;
+
+	#[inline]
+	fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+		bridge(self, consumer)
+	}
+
+	#[inline(always)]
+	fn opt_len(&self) -> Option<usize> {
+		Some(self.slice.len())
+	}
+}
+
+impl<T: Sync> IndexedParallelIterator for ParIter<'_, T> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.slice.len()
+	}
+
+	#[inline]
+	fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+		bridge(self, consumer)
+	}
+
+	#[inline]
+	fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+		callback.callback(IterProducer { slice: self.slice })
+	}
+}
+
+impl<T, A: Allocator> IdentitySet<T, A> {
+	/// Gets a parallel iterator of the contained keys.
+	///
+	/// As the backing buffer is a single contiguous, sorted slice, this splits directly on it, giving a cheap, balanced divide-and-conquer with no extra allocation.
+	#[inline]
+	pub fn par_iter(&self) -> ParIter<'_, T>
+	where
+		T: Sync,
+	{
+		ParIter::new(self.as_slice())
+	}
+
+	/// Drains the set in parallel, returning every key.
+	///
+	/// As `rayon` has no blanket parallel-iterator support for [`allocator_api2`]'s [`Vec`](allocator_api2::vec::Vec), the keys are first moved into a standard, globally-allocated [`Vec`](alloc::vec::Vec).
+	#[inline]
+	pub fn par_drain(&mut self) -> VecIntoIter<T>
+	where
+		T: Send,
+	{
+		let buf: StdVec<_> = self.as_mut_map().as_mut_vec().drain(..).map(|(key, _)| key).collect();
+		buf.into_par_iter()
+	}
+}
+
+impl<T, A> IdentitySet<T, A>
+where
+	T: Ord + Send,
+	A: Allocator,
+{
+	/// Extends the set from a parallel iterator of keys.
+	///
+	/// The incoming keys are gathered in parallel; merging them into the set's sorted buffer then reuses the same serial bulk-construction path as [`Extend::extend`](Extend::extend).
+	#[inline]
+	pub fn par_extend<I: IntoParallelIterator<Item = T>>(&mut self, iter: I) {
+		let keys: StdVec<_> = iter.into_par_iter().collect();
+		self.extend(keys);
+	}
+}
+
+/// The slice length below which [`par_union`](IdentitySet::par_union) and [`par_intersection`](IdentitySet::par_intersection) fall back to a plain, serial two-pointer merge instead of splitting further.
+const PAR_MERGE_THRESHOLD: usize = 0x400;
+
+fn serial_union<T: Ord + Clone>(lhs: &[T], rhs: &[T]) -> StdVec<T> {
+	let mut buf = StdVec::with_capacity(lhs.len() + rhs.len());
+
+	let (mut i, mut j) = (0x0, 0x0);
+
+	while i < lhs.len() && j < rhs.len() {
+		match lhs[i].cmp(&rhs[j]) {
+			Ordering::Less => { buf.push(lhs[i].clone()); i += 0x1; }
+			Ordering::Greater => { buf.push(rhs[j].clone()); j += 0x1; }
+
+			Ordering::Equal => {
+				buf.push(lhs[i].clone());
+
+				i += 0x1;
+				j += 0x1;
+			}
+		}
+	}
+
+	buf.extend_from_slice(&lhs[i..]);
+	buf.extend_from_slice(&rhs[j..]);
+
+	buf
+}
+
+fn serial_intersection<T: Ord + Clone>(lhs: &[T], rhs: &[T]) -> StdVec<T> {
+	let mut buf = StdVec::new();
+
+	let (mut i, mut j) = (0x0, 0x0);
+
+	while i < lhs.len() && j < rhs.len() {
+		match lhs[i].cmp(&rhs[j]) {
+			Ordering::Less    => i += 0x1,
+			Ordering::Greater => j += 0x1,
+
+			Ordering::Equal => {
+				buf.push(lhs[i].clone());
+
+				i += 0x1;
+				j += 0x1;
+			}
+		}
+	}
+
+	buf
+}
+
+/// Recursively merges two sorted, duplicate-free slices in parallel.
+///
+/// The larger slice is split at its midpoint, and a matching split point in the smaller slice is located via `partition_point`; the two halves are then merged by `op` independently and in parallel (via [`rayon::join`]), with no element ever straddling a split.
+fn par_merge<T, F>(lhs: &[T], rhs: &[T], op: &F) -> StdVec<T>
+where
+	T: Ord + Clone + Send + Sync,
+	F: Fn(&[T], &[T]) -> StdVec<T> + Sync,
+{
+	if lhs.len() + rhs.len() <= PAR_MERGE_THRESHOLD {
+		return op(lhs, rhs);
+	}
+
+	let (big, small, big_is_lhs) = if lhs.len() >= rhs.len() { (lhs, rhs, true) } else { (rhs, lhs, false) };
+
+	let mid = big.len() / 0x2;
+
+	let (big_lo, big_hi) = big.split_at(mid);
+
+	let split = small.partition_point(|key| key < &big[mid]);
+
+	let (small_lo, small_hi) = small.split_at(split);
+
+	let (mut lo, hi) = if big_is_lhs {
+		rayon::join(
+			|| par_merge(big_lo, small_lo, op),
+			|| par_merge(big_hi, small_hi, op),
+		)
+	} else {
+		rayon::join(
+			|| par_merge(small_lo, big_lo, op),
+			|| par_merge(small_hi, big_hi, op),
+		)
+	};
+
+	lo.extend(hi);
+
+	lo
+}
+
+impl<T, A> IdentitySet<T, A>
+where
+	T: Ord + Clone + Send + Sync,
+	A: Allocator,
+{
+	/// Computes the [union](https://en.wikipedia.org/wiki/Union_(set_theory)) of two sets in parallel, collecting the result into a newly-allocated set.
+	///
+	/// Unlike [`union`](Self::union), which lazily walks both sets serially, this recursively splits the larger of the two sorted slices and merges each half in parallel via [`rayon::join`], falling back to a plain two-pointer merge below a size threshold.
+	#[inline]
+	#[must_use]
+	pub fn par_union(&self, other: &Self) -> IdentitySet<T> {
+		let buf = par_merge(self.as_slice(), other.as_slice(), &serial_union);
+
+		// `par_merge` with `serial_union` produces a strictly
+		// ascending, duplicate-free sequence from two such se-
+		// quences.
+		IdentitySet::from_sorted(buf)
+	}
+
+	/// Computes the [intersection](https://en.wikipedia.org/wiki/Intersection_(set_theory)) of two sets in parallel, collecting the result into a newly-allocated set.
+	///
+	/// See [`par_union`](Self::par_union) for the parallelisation strategy.
+	#[inline]
+	#[must_use]
+	pub fn par_intersection(&self, other: &Self) -> IdentitySet<T> {
+		let buf = par_merge(self.as_slice(), other.as_slice(), &serial_intersection);
+
+		// `par_merge` with `serial_intersection` produces a
+		// strictly ascending, duplicate-free sequence from two
+		// such sequences.
+		IdentitySet::from_sorted(buf)
+	}
+}
+
+impl<T, A: Allocator> IntoParallelIterator for IdentitySet<T, A>
+where
+	T: Send,
+{
+	type Item = T;
+	type Iter = VecIntoIter<T>;
+
+	/// As `rayon` has no blanket parallel-iterator support for [`allocator_api2`]'s [`Vec`](allocator_api2::vec::Vec), the keys are first moved into a standard, globally-allocated [`Vec`](alloc::vec::Vec).
+	#[inline]
+	fn into_par_iter(self) -> Self::Iter {
+		let buf: StdVec<_> = self.into_map().into_vec().into_iter().map(|(key, _)| key).collect();
+		buf.into_par_iter()
+	}
+}
+
+impl<'a, T, A: Allocator> IntoParallelIterator for &'a IdentitySet<T, A>
+where
+	T: Sync,
+{
+	type Item = &'a T;
+	type Iter = ParIter<'a, T>;
+
+	#[inline(always)]
+	fn into_par_iter(self) -> Self::Iter {
+		self.par_iter()
+	}
+}
+
+impl<T: Ord + Send> FromParallelIterator<T> for IdentitySet<T> {
+	/// Gathers the incoming keys in parallel into a temporary buffer, then builds the set through the same serial bulk-construction path as [`FromIterator::from_iter`](FromIterator::from_iter).
+	#[inline]
+	fn from_par_iter<I: IntoParallelIterator<Item = T>>(par_iter: I) -> Self {
+		let keys: StdVec<_> = par_iter.into_par_iter().collect();
+		Self::from_iter(keys)
+	}
+}
+
+impl<T, A> ParallelExtend<T> for IdentitySet<T, A>
+where
+	T: Ord + Send,
+	A: Allocator,
+{
+	#[inline(always)]
+	fn par_extend<I: IntoParallelIterator<Item = T>>(&mut self, par_iter: I) {
+		self.par_extend(par_iter);
+	}
+}
@@ -0,0 +1,101 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::identity_map;
+
+use core::borrow::Borrow;
+use core::fmt::{self, Debug, Formatter};
+
+/// A borrowed, sorted view of an [identity set](crate::IdentitySet)'s keys.
+///
+/// This is constructed by the [`entries`](crate::IdentitySet::entries) method on [`IdentitySet`](crate::IdentitySet), or by [`split_at`](Self::split_at) on an existing slice.
+#[must_use]
+#[derive(Clone, Copy)]
+pub struct Slice<'a, T> {
+	slice: identity_map::Slice<'a, T, ()>,
+}
+
+impl<'a, T> Slice<'a, T> {
+	#[inline(always)]
+	pub(crate) fn new(slice: identity_map::Slice<'a, T, ()>) -> Self {
+		Self { slice }
+	}
+
+	/// Gets the number of keys in the slice.
+	#[inline(always)]
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.slice.len()
+	}
+
+	/// Checks if the slice is empty.
+	#[inline(always)]
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.slice.is_empty()
+	}
+
+	/// Borrows the first key.
+	#[inline(always)]
+	#[must_use]
+	pub fn first(&self) -> Option<&'a T> {
+		self.slice.first().map(|(key, _)| key)
+	}
+
+	/// Borrows the last key.
+	#[inline(always)]
+	#[must_use]
+	pub fn last(&self) -> Option<&'a T> {
+		self.slice.last().map(|(key, _)| key)
+	}
+
+	/// Borrows the key at the specified index.
+	#[inline(always)]
+	#[must_use]
+	pub fn get_index(&self, index: usize) -> Option<&'a T> {
+		self.slice.get_index(index).map(|(key, _)| key)
+	}
+
+	/// Binary-searches the slice for a key.
+	///
+	/// # Errors
+	///
+	/// If the key is not found, then the index at which it could be inserted whilst maintaining order is returned instead.
+	#[inline(always)]
+	pub fn binary_search<U>(&self, key: &U) -> Result<usize, usize>
+	where
+		T: Borrow<U>,
+		U: Ord + ?Sized,
+	{
+		self.slice.binary_search(key)
+	}
+
+	/// Returns the partition point of the slice according to the given predicate.
+	///
+	/// The slice is assumed to already be partitioned according to the predicate.
+	#[inline(always)]
+	#[must_use]
+	pub fn partition_point<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize {
+		self.slice.partition_point(|(key, _)| pred(key))
+	}
+
+	/// Divides the slice into two at an index.
+	///
+	/// # Panics
+	///
+	/// Panics if `mid` is greater than the slice's length.
+	#[inline(always)]
+	#[must_use]
+	#[track_caller]
+	pub fn split_at(&self, mid: usize) -> (Self, Self) {
+		let (lo, hi) = self.slice.split_at(mid);
+
+		(Self::new(lo), Self::new(hi))
+	}
+}
+
+impl<T: Debug> Debug for Slice<'_, T> {
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.debug_tuple("Slice").field(&self.slice).finish()
+	}
+}
@@ -0,0 +1,108 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::identity_set::IdentitySet;
+
+use alloc::collections::BinaryHeap;
+use allocator_api2::alloc::Allocator;
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Formatter};
+use core::iter::FusedIterator;
+use core::slice::Iter as SliceIter;
+
+/// A single set's current head and remaining elements, ordered for a min-heap on `head`.
+struct HeapEntry<'a, T> {
+	head: &'a T,
+	rest: SliceIter<'a, T>,
+}
+
+impl<T: Ord> PartialEq for HeapEntry<'_, T> {
+	#[inline(always)]
+	fn eq(&self, other: &Self) -> bool {
+		self.head == other.head
+	}
+}
+
+impl<T: Ord> Eq for HeapEntry<'_, T> { }
+
+impl<T: Ord> PartialOrd for HeapEntry<'_, T> {
+	#[inline(always)]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl<T: Ord> Ord for HeapEntry<'_, T> {
+	#[inline(always)]
+	fn cmp(&self, other: &Self) -> Ordering {
+		// Reversed so that `BinaryHeap` -- which is
+		// otherwise a max-heap -- pops the smallest
+		// head first.
+
+		other.head.cmp(self.head)
+	}
+}
+
+/// Iterator denoting the [union](https://en.wikipedia.org/wiki/Union_(set_theory)) across any number of [identity sets](IdentitySet).
+///
+/// This is constructed by the [`union_all`](IdentitySet::union_all) function.
+///
+/// Every source set is already sorted, so the sets are merged in a single streaming pass using a min-heap of their current heads, rather than by folding pairwise [unions](IdentitySet::union).
+#[must_use]
+pub struct UnionAll<'a, T> {
+	heap: BinaryHeap<HeapEntry<'a, T>>,
+	last: Option<&'a T>,
+}
+
+impl<'a, T: Ord> UnionAll<'a, T> {
+	/// Constructs a new iterator denoting the union across any number of identity sets.
+	pub(crate) fn new<A, I>(sets: I) -> Self
+	where
+		A: Allocator + 'a,
+		I: IntoIterator<Item = &'a IdentitySet<T, A>>,
+	{
+		let mut heap = BinaryHeap::new();
+
+		for set in sets {
+			let mut rest = set.as_slice().iter();
+
+			if let Some(head) = rest.next() {
+				heap.push(HeapEntry { head, rest });
+			}
+		}
+
+		Self { heap, last: None }
+	}
+}
+
+impl<T: Debug> Debug for UnionAll<'_, T> {
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.debug_struct("UnionAll").finish_non_exhaustive()
+	}
+}
+
+impl<T: Ord> FusedIterator for UnionAll<'_, T> { }
+
+impl<'a, T: Ord> Iterator for UnionAll<'a, T> {
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			let HeapEntry { head, mut rest } = self.heap.pop()?;
+
+			if let Some(next) = rest.next() {
+				self.heap.push(HeapEntry { head: next, rest });
+			}
+
+			// Skip further heap entries equal to the last
+			// emitted value, so that each distinct value is
+			// only yielded once.
+
+			if self.last == Some(head) { continue };
+
+			self.last = Some(head);
+
+			return Some(head);
+		}
+	}
+}
@@ -0,0 +1,218 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::identity_table::Iter;
+use crate::IdentityIndex;
+
+use allocator_api2::alloc::{Allocator, Global};
+use allocator_api2::vec::Vec;
+use core::fmt::{self, Debug, Formatter};
+use core::marker::PhantomData;
+
+/// A direct-address identity table.
+///
+/// Unlike [`IdentityMap`](crate::IdentityMap), which keeps keys in a sorted buffer and locates them by binary search, this type keys directly into a flat slot buffer using [`IdentityIndex::index`].
+/// This gives *O*(1) [`get`](Self::get), [`insert`](Self::insert), and [`remove`](Self::remove) with no key comparisons whatsoever, at the cost of eagerly allocating [`K::DOMAIN`](IdentityIndex::DOMAIN) slots up front.
+///
+/// As the allocation is proportional to the key's whole domain rather than to the number of pairs actually stored, this type is only suitable for key types with a small, bounded domain -- such as `u8` or `u16`.
+pub struct IdentityTable<K, V, A: Allocator = Global>
+where
+	K: IdentityIndex,
+{
+	slots: Vec<Option<V>, A>,
+	len:   usize,
+
+	_key: PhantomData<fn(K)>,
+}
+
+impl<K, V> IdentityTable<K, V>
+where
+	K: IdentityIndex,
+{
+	/// Constructs a new, empty identity table.
+	///
+	/// This eagerly allocates `K::DOMAIN` slots using the global allocator.
+	#[inline(always)]
+	#[must_use]
+	#[track_caller]
+	pub fn new() -> Self {
+		Self::new_in(Global)
+	}
+}
+
+impl<K, V, A> Default for IdentityTable<K, V, A>
+where
+	K: IdentityIndex,
+	A: Allocator + Default,
+{
+	#[inline(always)]
+	fn default() -> Self {
+		Self::new_in(Default::default())
+	}
+}
+
+impl<K, V, A> IdentityTable<K, V, A>
+where
+	K: IdentityIndex,
+	A: Allocator,
+{
+	/// Constructs a new, empty identity table with a specific allocator.
+	///
+	/// This eagerly allocates `K::DOMAIN` slots using `alloc`.
+	#[inline]
+	#[must_use]
+	#[track_caller]
+	pub fn new_in(alloc: A) -> Self {
+		let mut slots = Vec::with_capacity_in(K::DOMAIN, alloc);
+		slots.resize_with(K::DOMAIN, || None);
+
+		Self { slots, len: 0x0, _key: PhantomData }
+	}
+
+	/// Clears the table.
+	///
+	/// All contained values are dropped after a call to this method.
+	/// The length counter is then reset to zero.
+	#[inline]
+	pub fn clear(&mut self) {
+		for slot in &mut self.slots {
+			*slot = None;
+		}
+
+		self.len = 0x0;
+	}
+
+	/// Borrows the table's allocator.
+	#[inline(always)]
+	#[must_use]
+	pub fn allocator(&self) -> &A {
+		self.slots.allocator()
+	}
+
+	/// Gets an iterator of the contained values.
+	#[inline(always)]
+	pub fn iter(&self) -> Iter<'_, V> {
+		Iter::new(&self.slots)
+	}
+
+	/// Retrieves the total capacity of the table.
+	///
+	/// This is always exactly [`K::DOMAIN`](IdentityIndex::DOMAIN).
+	#[inline(always)]
+	#[must_use]
+	pub fn capacity(&self) -> usize {
+		self.slots.len()
+	}
+
+	/// Retrieves the current length of the table.
+	#[allow(clippy::len_without_is_empty)]
+	#[inline(always)]
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Tests if the table is empty.
+	#[inline(always)]
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.len == 0x0
+	}
+
+	/// Checks if the table contains the specified key.
+	#[inline(always)]
+	#[must_use]
+	pub fn contains_key(&self, key: &K) -> bool {
+		self.get(key).is_some()
+	}
+
+	/// Borrows the associated value of a key.
+	#[inline]
+	#[must_use]
+	pub fn get(&self, key: &K) -> Option<&V> {
+		self.slots.get(key.index()).and_then(Option::as_ref)
+	}
+
+	/// Mutably borrows the associated value of a key.
+	#[inline]
+	#[must_use]
+	pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+		self.slots.get_mut(key.index()).and_then(Option::as_mut)
+	}
+
+	/// Inserts a new key-value pair into the table.
+	///
+	/// If the provided key already exists in the table, then its associated value is simply updated.
+	/// The previous value is in that case returned from this method.
+	///
+	/// # Panics
+	///
+	/// This method will panic if `key.index()` is not less than `K::DOMAIN`.
+	#[inline]
+	#[track_caller]
+	pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+		let slot = &mut self.slots[key.index()];
+
+		let prev = slot.replace(value);
+
+		if prev.is_none() { self.len += 0x1 };
+
+		prev
+	}
+
+	/// Removes the whole pair associated with the specific key.
+	///
+	/// The associated value is returned from this method.
+	/// If no pair existed with the provided key, then this method will instead return a [`None`] instance.
+	#[inline]
+	pub fn remove(&mut self, key: &K) -> Option<V> {
+		let slot = self.slots.get_mut(key.index())?;
+		let prev = slot.take();
+
+		if prev.is_some() { self.len -= 0x1 };
+
+		prev
+	}
+}
+
+impl<K, V, A> Clone for IdentityTable<K, V, A>
+where
+	K: IdentityIndex,
+	V: Clone,
+	A: Allocator + Clone,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		Self {
+			slots: self.slots.clone(),
+			len:   self.len,
+
+			_key: PhantomData,
+		}
+	}
+}
+
+impl<K, V, A> Debug for IdentityTable<K, V, A>
+where
+	K: IdentityIndex,
+	V: Debug,
+	A: Allocator,
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		Debug::fmt(&self.iter(), f)
+	}
+}
+
+impl<'a, K, V, A: Allocator> IntoIterator for &'a IdentityTable<K, V, A>
+where
+	K: IdentityIndex,
+{
+	type Item = &'a V;
+
+	type IntoIter = Iter<'a, V>;
+
+	#[inline(always)]
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
@@ -0,0 +1,69 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use core::fmt::{self, Debug, Formatter};
+use core::iter::FusedIterator;
+use core::slice;
+
+/// An iterator over the occupied slots of an [identity table](crate::identity_table::IdentityTable).
+///
+/// This is constructed by the [`iter`](crate::identity_table::IdentityTable::iter) method on [`IdentityTable`](crate::identity_table::IdentityTable).
+pub struct Iter<'a, V> {
+	inner: slice::Iter<'a, Option<V>>,
+}
+
+impl<'a, V> Iter<'a, V> {
+	#[inline(always)]
+	pub(crate) fn new(slots: &'a [Option<V>]) -> Self {
+		Self { inner: slots.iter() }
+	}
+}
+
+impl<V> Debug for Iter<'_, V>
+where
+	V: Debug,
+{
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.debug_list().entries(self.clone()).finish()
+	}
+}
+
+impl<V> Clone for Iter<'_, V> {
+	#[inline(always)]
+	fn clone(&self) -> Self {
+		Self { inner: self.inner.clone() }
+	}
+}
+
+impl<V> Default for Iter<'_, V> {
+	#[inline(always)]
+	fn default() -> Self {
+		Self { inner: [].iter() }
+	}
+}
+
+impl<'a, V> DoubleEndedIterator for Iter<'a, V> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		while let Some(slot) = self.inner.next_back() {
+			if let Some(value) = slot { return Some(value) };
+		}
+
+		None
+	}
+}
+
+impl<'a, V> Iterator for Iter<'a, V> {
+	type Item = &'a V;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		while let Some(slot) = self.inner.next() {
+			if let Some(value) = slot { return Some(value) };
+		}
+
+		None
+	}
+}
+
+impl<V> FusedIterator for Iter<'_, V> { }
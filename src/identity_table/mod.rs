@@ -0,0 +1,9 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+//! The [`IdentityTable`] type and associated facilities.
+
+mod identity_table;
+mod iter;
+
+pub use identity_table::IdentityTable;
+pub use iter::Iter;
@@ -27,11 +27,29 @@ extern crate alloc;
 #[cfg(doc)]
 extern crate std;
 
+pub mod error;
+pub mod identity_index;
 pub mod identity_map;
 pub mod identity_set;
+pub mod identity_table;
+
+#[cfg(feature = "serde")]
+pub mod serde_seq;
+
+#[doc(inline)]
+pub use crate::error::TryInsertError;
+
+#[doc(inline)]
+pub use crate::error::TryReserveError;
+
+#[doc(inline)]
+pub use crate::identity_index::IdentityIndex;
 
 #[doc(inline)]
 pub use crate::identity_map::IdentityMap;
 
 #[doc(inline)]
 pub use crate::identity_set::IdentitySet;
+
+#[doc(inline)]
+pub use crate::identity_table::IdentityTable;
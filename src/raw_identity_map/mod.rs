@@ -29,7 +29,7 @@
 use alloc::alloc::{Allocator, Global};
 use core::alloc::Layout;
 use core::any::type_name;
-use core::mem::ManuallyDrop;
+use core::mem::{ManuallyDrop, size_of};
 use core::ptr::{self, copy_nonoverlapping, NonNull};
 
 // NOTE: `cap` can always safely be cast to `usize`
@@ -122,9 +122,50 @@ impl<K, V, A: Allocator> RawIdentityMap<K, V, A> {
 		self.ptr = ptr.cast();
 	}
 
+	/// Reserves additional capacity for at least `count` more elements, using an amortized growth strategy.
+	///
+	/// Rather than growing to the exact requested capacity, the buffer is grown to `max(len + count, capacity * 2)`, with a small nonzero floor on the very first allocation.
+	/// This turns a loop of single-element insertions into amortized *O*(1) per element instead of *O*(*n*) per element.
+	///
+	/// # Panics
+	///
+	/// See [`reserve_exact`](Self::reserve_exact).
 	#[inline]
 	#[track_caller]
 	pub fn reserve(&mut self, count: usize) {
+		if self.is_allocated() {
+			// Use a nonzero floor for the first allocation so
+			// that a loop of single-element insertions does
+			// not immediately re-allocate. Zero-sized types
+			// need no such floor, as they never actually
+			// allocate.
+
+			let count = if size_of::<(K, V)>() == 0x0 { count } else { count.max(0x4) };
+
+			self.allocate(count);
+			return;
+		}
+
+		let len     = self.len();
+		let old_cap = self.capacity();
+
+		let required = len + count;
+		let new_cap  = required.max(old_cap.saturating_mul(0x2));
+
+		self.reserve_exact(new_cap - old_cap);
+	}
+
+	/// Reserves additional capacity for exactly `count` more elements.
+	///
+	/// Unlike [`reserve`](Self::reserve), this method does not use an amortized growth strategy: the buffer is grown to hold exactly `count` more elements than its current capacity.
+	/// This is appropriate for callers -- such as `with_capacity`/`shrink_to_fit` -- that already know the exact capacity they want.
+	///
+	/// # Panics
+	///
+	/// This method will panic if the new capacity overflows [`isize::MAX`], or if the allocator could not fulfil the allocation.
+	#[inline]
+	#[track_caller]
+	pub fn reserve_exact(&mut self, count: usize) {
 		// Do not grow if not already allocated.
 
 		if self.is_allocated() {
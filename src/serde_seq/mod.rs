@@ -0,0 +1,96 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+//! Sequence-based [`serde`] representation for [`IdentityMap`](crate::IdentityMap).
+//!
+//! The ordinary [`Serialize`]/[`Deserialize`] impls on [`IdentityMap`](crate::IdentityMap) go through [`collect_map`](Serializer::collect_map)/[`deserialize_map`](Deserializer::deserialize_map), which requires self-describing formats such as JSON to encode keys as strings.
+//! This module instead encodes the map as a sequence of `(K, V)` pairs, so that non-string key types round-trip losslessly across all formats.
+//!
+//! Use it with `#[serde(with = "identity_map::serde_seq")]` on a field of type [`IdentityMap`](crate::IdentityMap).
+
+use crate::identity_map::IdentityMap;
+
+use alloc::vec::Vec as StdVec;
+use allocator_api2::alloc::Allocator;
+use core::any::type_name;
+use core::fmt::{self, Formatter};
+use core::marker::PhantomData;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::{SeqAccess, Visitor};
+
+#[expect(clippy::type_complexity)]
+#[repr(transparent)]
+#[derive(Default)]
+struct SeqVisitor<K, V, A: Allocator> {
+	_map: PhantomData<fn() -> IdentityMap<K, V, A>>,
+}
+
+impl<K, V, A: Allocator> SeqVisitor<K, V, A> {
+	#[inline(always)]
+	#[must_use]
+	pub const fn new() -> Self {
+		Self { _map: PhantomData }
+	}
+}
+
+impl<'de, K, V, Alloc> Visitor<'de> for SeqVisitor<K, V, Alloc>
+where
+	K:     Deserialize<'de> + Ord,
+	V:     Deserialize<'de>,
+	Alloc: Allocator + Default,
+{
+	type Value = IdentityMap<K, V, Alloc>;
+
+	#[inline(always)]
+	fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+		let key_name   = type_name::<K>();
+		let value_name = type_name::<V>();
+
+		write!(formatter, "a sequence of `({key_name}, {value_name})` pairs")
+	}
+
+	#[inline]
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		let cap = seq.size_hint().unwrap_or_default();
+
+		let mut buf = StdVec::with_capacity(cap);
+
+		while let Some(entry) = seq.next_element()? {
+			buf.push(entry);
+		}
+
+		// Building from the collected entries in one pass
+		// sorts and dedups once, rather than doing a binary
+		// search and shift for every entry.
+
+		Ok(IdentityMap::from_iter(buf))
+	}
+}
+
+/// Deserialises an [`IdentityMap`] from a sequence of `(K, V)` pairs.
+///
+/// For use with `#[serde(with = "identity_map::serde_seq")]`.
+#[inline(always)]
+pub fn deserialize<'de, K, V, A, D>(deserialiser: D) -> Result<IdentityMap<K, V, A>, D::Error>
+where
+	K: Deserialize<'de> + Ord,
+	V: Deserialize<'de>,
+	A: Allocator + Default,
+	D: Deserializer<'de>,
+{
+	deserialiser.deserialize_seq(SeqVisitor::<K, V, A>::new())
+}
+
+/// Serialises an [`IdentityMap`] as a sequence of `(K, V)` pairs.
+///
+/// For use with `#[serde(with = "identity_map::serde_seq")]`.
+#[inline(always)]
+pub fn serialize<K, V, A, S>(map: &IdentityMap<K, V, A>, serialiser: S) -> Result<S::Ok, S::Error>
+where
+	K: Serialize,
+	V: Serialize,
+	A: Allocator,
+	S: Serializer,
+{
+	serialiser.collect_seq(map.iter())
+}